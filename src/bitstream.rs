@@ -0,0 +1,278 @@
+//! Little-endian bitstreams for packing field elements into 31 bits each.
+//!
+//! Layout: bit `k` of the stream is bit `k % 8` of byte `k / 8`, and every
+//! value is written least significant bit first. A final partial byte is
+//! padded with zero bits.
+
+use std::fmt;
+
+use crate::fields::basefield::{BaseField, PRIME};
+
+pub const BASE_FIELD_BITS: u32 = 31;
+
+/// Error returned by `BitReader::read_base_field`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BitstreamError {
+    /// Fewer than 31 bits remained.
+    UnexpectedEnd,
+    /// The 31 bits encoded the non-canonical value `PRIME`.
+    NonCanonical,
+}
+
+impl fmt::Display for BitstreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BitstreamError::UnexpectedEnd => write!(f, "unexpected end of bitstream"),
+            BitstreamError::NonCanonical => write!(f, "value is not below PRIME"),
+        }
+    }
+}
+
+impl std::error::Error for BitstreamError {}
+
+#[derive(Clone, Debug, Default)]
+pub struct BitWriter {
+    bytes: Vec<u8>,
+    acc: u64,
+    n_acc: u32,
+}
+
+impl BitWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends the low `n_bits` bits of `value`. Panics if `n_bits > 32` or
+    /// if `value` does not fit in `n_bits` bits.
+    pub fn write_bits(&mut self, value: u32, n_bits: u32) {
+        assert!(n_bits <= 32, "at most 32 bits per write");
+        assert!(
+            n_bits == 32 || value >> n_bits == 0,
+            "value does not fit in {n_bits} bits"
+        );
+        self.acc |= (value as u64) << self.n_acc;
+        self.n_acc += n_bits;
+        while self.n_acc >= 8 {
+            self.bytes.push(self.acc as u8);
+            self.acc >>= 8;
+            self.n_acc -= 8;
+        }
+    }
+
+    pub fn write_base_field(&mut self, value: BaseField) {
+        self.write_bits(value.0, BASE_FIELD_BITS);
+    }
+
+    /// Number of bits written so far.
+    pub fn bit_len(&self) -> usize {
+        self.bytes.len() * 8 + self.n_acc as usize
+    }
+
+    /// Returns the stream, zero-padded to a whole number of bytes.
+    pub fn finish(mut self) -> Vec<u8> {
+        if self.n_acc > 0 {
+            self.bytes.push(self.acc as u8);
+        }
+        self.bytes
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct BitReader<'a> {
+    bytes: &'a [u8],
+    bit_pos: usize,
+}
+
+impl<'a> BitReader<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        BitReader { bytes, bit_pos: 0 }
+    }
+
+    /// Reads the next `n_bits` bits, or returns `None` if fewer remain.
+    /// Panics if `n_bits > 32`.
+    pub fn read_bits(&mut self, n_bits: u32) -> Option<u32> {
+        assert!(n_bits <= 32, "at most 32 bits per read");
+        if n_bits as usize > self.remaining_bits() {
+            return None;
+        }
+        let mut res = 0u64;
+        let mut n_read = 0;
+        while n_read < n_bits {
+            let offset = (self.bit_pos % 8) as u32;
+            let take = (8 - offset).min(n_bits - n_read);
+            let bits = (self.bytes[self.bit_pos / 8] >> offset) as u64 & ((1 << take) - 1);
+            res |= bits << n_read;
+            n_read += take;
+            self.bit_pos += take as usize;
+        }
+        Some(res as u32)
+    }
+
+    /// Reads a 31-bit element. On error the reader does not advance.
+    pub fn read_base_field(&mut self) -> Result<BaseField, BitstreamError> {
+        let start = self.bit_pos;
+        let value = self
+            .read_bits(BASE_FIELD_BITS)
+            .ok_or(BitstreamError::UnexpectedEnd)?;
+        if value >= PRIME {
+            self.bit_pos = start;
+            return Err(BitstreamError::NonCanonical);
+        }
+        Ok(BaseField(value))
+    }
+
+    pub fn remaining_bits(&self) -> usize {
+        self.bytes.len() * 8 - self.bit_pos
+    }
+}
+
+/// Bits per limb in `digest_to_limbs`. Below 31, so every limb is canonical.
+pub const DIGEST_LIMB_BITS: u32 = 30;
+
+/// Number of limbs a 32-byte digest splits into: eight full 30-bit limbs
+/// and a ninth holding the top 16 bits.
+pub const DIGEST_N_LIMBS: usize = 9;
+
+/// Splits a 32-byte digest into limbs using the bitstream layout above:
+/// limb `k` holds digest bits `[30k, min(30k + 30, 256))`, least
+/// significant bit first. The map is injective and every limb is below
+/// `2^30`, so no reduction takes place.
+pub fn digest_to_limbs(digest: &[u8; 32]) -> [BaseField; DIGEST_N_LIMBS] {
+    let mut reader = BitReader::new(digest);
+    std::array::from_fn(|_| {
+        let n_bits = DIGEST_LIMB_BITS.min(reader.remaining_bits() as u32);
+        BaseField(reader.read_bits(n_bits).unwrap())
+    })
+}
+
+/// Inverse of `digest_to_limbs`. Returns `None` if a limb has bits set
+/// above its width, i.e. the limbs did not come from a digest.
+pub fn limbs_to_digest(limbs: &[BaseField; DIGEST_N_LIMBS]) -> Option<[u8; 32]> {
+    let mut writer = BitWriter::new();
+    let mut remaining = 256;
+    for limb in limbs {
+        let n_bits = DIGEST_LIMB_BITS.min(remaining);
+        if limb.0 >> n_bits != 0 {
+            return None;
+        }
+        writer.write_bits(limb.0, n_bits);
+        remaining -= n_bits;
+    }
+    writer.finish().try_into().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn test_layout() {
+        let mut writer = BitWriter::new();
+        writer.write_bits(0b101, 3);
+        writer.write_bits(0b11111, 5);
+        writer.write_bits(0x1ff, 9);
+        assert_eq!(writer.bit_len(), 17);
+        assert_eq!(writer.finish(), vec![0b1111_1101, 0xff, 0b1]);
+
+        let mut writer = BitWriter::new();
+        writer.write_base_field(BaseField::new(PRIME - 1));
+        writer.write_base_field(BaseField::new(1));
+        assert_eq!(
+            writer.finish(),
+            vec![0xfe, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00]
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        for _ in 0..100 {
+            let items: Vec<(u32, u32)> = (0..rng.gen_range(0..50))
+                .map(|_| {
+                    let n_bits = rng.gen_range(0..=32);
+                    let value = if n_bits == 0 {
+                        0
+                    } else {
+                        rng.gen::<u32>() >> (32 - n_bits)
+                    };
+                    (value, n_bits)
+                })
+                .collect();
+            let mut writer = BitWriter::new();
+            for &(value, n_bits) in &items {
+                writer.write_bits(value, n_bits);
+            }
+            let total_bits = writer.bit_len();
+            let bytes = writer.finish();
+            assert_eq!(bytes.len(), total_bits.div_ceil(8));
+
+            let mut reader = BitReader::new(&bytes);
+            for &(value, n_bits) in &items {
+                assert_eq!(reader.read_bits(n_bits), Some(value));
+            }
+            assert!(reader.remaining_bits() < 8);
+        }
+    }
+
+    #[test]
+    fn test_base_field_round_trip() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let values: Vec<BaseField> = (0..100).map(|_| BaseField::new(rng.gen())).collect();
+        let mut writer = BitWriter::new();
+        for v in &values {
+            writer.write_base_field(*v);
+        }
+        let bytes = writer.finish();
+        assert_eq!(bytes.len(), (100 * 31usize).div_ceil(8));
+
+        let mut reader = BitReader::new(&bytes);
+        for v in &values {
+            assert_eq!(reader.read_base_field(), Ok(*v));
+        }
+        assert_eq!(reader.read_base_field(), Err(BitstreamError::UnexpectedEnd));
+    }
+
+    #[test]
+    fn test_reader_rejects() {
+        let bytes = [0xff, 0xff, 0xff, 0x7f];
+        let mut reader = BitReader::new(&bytes);
+        assert_eq!(reader.read_base_field(), Err(BitstreamError::NonCanonical));
+        assert_eq!(reader.remaining_bits(), 32);
+        assert_eq!(reader.read_bits(32), Some(0x7fff_ffff));
+
+        let mut reader = BitReader::new(&bytes[..3]);
+        assert_eq!(reader.read_base_field(), Err(BitstreamError::UnexpectedEnd));
+        assert_eq!(reader.remaining_bits(), 24);
+        assert_eq!(reader.read_bits(25), None);
+    }
+
+    #[test]
+    fn test_digest_limbs() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        for _ in 0..100 {
+            let digest: [u8; 32] = rng.gen();
+            let limbs = digest_to_limbs(&digest);
+            assert!(limbs.iter().all(|limb| limb.0 < 1 << DIGEST_LIMB_BITS));
+            assert_eq!(limbs_to_digest(&limbs), Some(digest));
+        }
+
+        let mut digest = [0; 32];
+        digest[0] = 0x01;
+        digest[3] = 0xc0;
+        digest[31] = 0x80;
+        let limbs = digest_to_limbs(&digest);
+        assert_eq!(limbs[0], BaseField(1));
+        assert_eq!(limbs[1], BaseField(3));
+        assert_eq!(limbs[8], BaseField(0x8000));
+        assert!(limbs[2..8].iter().all(|limb| limb.0 == 0));
+
+        let mut bad = limbs;
+        bad[8] = BaseField(1 << 16);
+        assert_eq!(limbs_to_digest(&bad), None);
+        bad[8] = BaseField(0);
+        bad[0] = BaseField(1 << DIGEST_LIMB_BITS);
+        assert_eq!(limbs_to_digest(&bad), None);
+    }
+}
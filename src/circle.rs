@@ -0,0 +1,63 @@
+//! Points on the circle `x^2 + y^2 = 1` over the extension field.
+
+use crate::fields::{extensionfield::ExtensionField, Field};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CirclePoint {
+    pub x: ExtensionField,
+    pub y: ExtensionField,
+}
+
+impl CirclePoint {
+    /// Maps a drawn element `t` to the point
+    /// `((1 - t^2) / (1 + t^2), 2t / (1 + t^2))`, the stereographic
+    /// parametrization of the circle. Prover and verifier must both derive
+    /// the OODS point through this map.
+    ///
+    /// Returns `None` for `t = ±i`, the only inputs with `1 + t^2 = 0`; the
+    /// caller should draw again.
+    pub fn from_channel_draw(t: ExtensionField) -> Option<CirclePoint> {
+        let t2 = t.square();
+        let denom_inv = (ExtensionField::one() + t2).try_inverse()?;
+        Some(CirclePoint {
+            x: (ExtensionField::one() - t2) * denom_inv,
+            y: (t + t) * denom_inv,
+        })
+    }
+
+    pub fn is_on_circle(&self) -> bool {
+        self.x.square() + self.y.square() == ExtensionField::one()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fields::basefield::PRIME;
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn test_from_channel_draw() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        for _ in 0..100 {
+            let t = ExtensionField::new(rng.gen(), rng.gen(), rng.gen(), rng.gen());
+            assert!(CirclePoint::from_channel_draw(t).unwrap().is_on_circle());
+        }
+
+        let zero = CirclePoint::from_channel_draw(ExtensionField::zero()).unwrap();
+        assert_eq!(zero.x, ExtensionField::one());
+        assert_eq!(zero.y, ExtensionField::zero());
+        let one = CirclePoint::from_channel_draw(ExtensionField::one()).unwrap();
+        assert_eq!(one.x, ExtensionField::zero());
+        assert_eq!(one.y, ExtensionField::one());
+    }
+
+    #[test]
+    fn test_from_channel_draw_exceptions() {
+        let i = ExtensionField::new(0, 1, 0, 0);
+        let minus_i = ExtensionField::new(0, PRIME - 1, 0, 0);
+        assert_eq!(CirclePoint::from_channel_draw(i), None);
+        assert_eq!(CirclePoint::from_channel_draw(minus_i), None);
+    }
+}
@@ -1,18 +1,86 @@
-use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use super::{batch_inverse, batch_inverse_in_place, Field, ParseFieldError};
 
 pub const PRIME: u32 = (1 << 31) - 1;
 
-#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+/// Values built through `new`, `From` or arithmetic are canonical
+/// (`< PRIME`), so the derived `Eq`, `Hash` and `Ord` act on the canonical
+/// representative and `Default` is zero. The field is public, though:
+/// constructing `BaseField(x)` directly with `x >= PRIME` breaks `Eq`,
+/// `Hash` and `Ord`, e.g. `BaseField(PRIME) != BaseField(0)`.
+#[derive(Clone, Debug, PartialEq, Eq, Copy, Hash, PartialOrd, Ord, Default)]
+#[repr(transparent)]
 pub struct BaseField(pub u32);
 
 impl BaseField {
-    pub fn new(value: u32) -> Self {
+    pub const fn new(value: u32) -> Self {
         BaseField(value % PRIME)
     }
 
+    /// Maps negative values to `PRIME - |value|` (reduced), so that
+    /// `from_i32(-x) == -from_i32(x)`.
+    pub const fn from_i32(value: i32) -> Self {
+        let magnitude = BaseField::new(value.unsigned_abs());
+        if value < 0 {
+            magnitude.const_neg()
+        } else {
+            magnitude
+        }
+    }
+
+    /// `const` version of `+`, for building tables at compile time.
+    pub const fn const_add(self, other: BaseField) -> BaseField {
+        BaseField::reduce_once(self.0 + other.0)
+    }
+
+    /// `const` version of `-`. On underflow the difference wraps and adding
+    /// `PRIME` wraps it back into range, so both cases land in `[0, 2 * PRIME)`.
+    pub const fn const_sub(self, other: BaseField) -> BaseField {
+        BaseField::reduce_once(self.0.wrapping_sub(other.0).wrapping_add(PRIME))
+    }
+
+    /// `const` version of `*`.
+    pub const fn const_mul(self, other: BaseField) -> BaseField {
+        let prod = self.mul_unreduced(other);
+        // 2^31 = 1 mod p, so `hi * 2^31 + lo` folds to `hi + lo`.
+        let lo = (prod & PRIME as u64) as u32;
+        let hi = (prod >> 31) as u32;
+        BaseField::reduce_once(lo + hi)
+    }
+
+    /// `const` version of unary `-`.
+    pub const fn const_neg(self) -> BaseField {
+        BaseField::reduce_once(PRIME - self.0)
+    }
+
+    /// Fully reduces any `u64`, e.g. an accumulator built with
+    /// `mul_unreduced` and `partial_reduce`.
+    pub const fn from_unreduced(val: u64) -> Self {
+        // Two folds bring any u64 below `PRIME + 8`.
+        let folded = Self::partial_reduce(Self::partial_reduce(val));
+        BaseField::reduce_once(folded as u32)
+    }
+
+    /// Folds `val` once using `2^31 = 1 mod p`. The result is congruent to
+    /// `val` and below `2^34`, so it can keep accumulating before a final
+    /// `from_unreduced`.
+    pub const fn partial_reduce(val: u64) -> u64 {
+        (val & PRIME as u64) + (val >> 31)
+    }
+
+    /// Returns the product as a raw `u64` below `2^62`. Up to four such
+    /// products can be summed without overflow before reducing.
+    pub const fn mul_unreduced(self, other: BaseField) -> u64 {
+        self.0 as u64 * other.0 as u64
+    }
+
     pub fn pow(&self, exp: u128) -> Self {
         let mut res = BaseField::new(1);
-        let mut base = self.clone();
+        let mut base = *self;
         let mut exp = exp;
         while exp > 0 {
             if exp & 1 == 1 {
@@ -28,23 +96,169 @@ impl BaseField {
         self * self
     }
 
+    pub fn cube(self) -> Self {
+        self.square() * self
+    }
+
+    /// Computes `self^(2^log)` by repeated squaring.
+    pub fn exp_power_of_2(self, log: usize) -> Self {
+        let mut res = self;
+        for _ in 0..log {
+            res = res.square();
+        }
+        res
+    }
+
+    /// Same as `exp_power_of_2`: `self^(2^k)`.
+    pub fn pow2k(self, k: usize) -> Self {
+        self.exp_power_of_2(k)
+    }
+
+    /// Panics if `self` is zero; use `try_inverse` on untrusted input.
     pub fn inverse(self) -> BaseField {
-        assert!(self != BaseField::new(0));
-        let t0 = Self::sqn::<2>(self) * self;
+        self.try_inverse().expect("0 has no inverse")
+    }
+
+    /// Returns `None` for zero, otherwise `self^(p - 2)` via an addition chain.
+    pub fn try_inverse(&self) -> Option<BaseField> {
+        if self.0 == 0 {
+            return None;
+        }
+        let v = *self;
+        let t0 = Self::sqn::<2>(v) * v;
         let t1 = Self::sqn::<1>(t0) * t0;
         let t2 = Self::sqn::<3>(t1) * t0;
         let t3 = Self::sqn::<1>(t2) * t0;
         let t4 = Self::sqn::<8>(t3) * t3;
         let t5 = Self::sqn::<8>(t4) * t3;
-        Self::sqn::<7>(t5) * t2
+        Some(Self::sqn::<7>(t5) * t2)
+    }
+
+    /// Returns a square root of `self`, or `None` if `self` is not a square.
+    /// Since `p = 3 mod 4` the candidate is `self^((p + 1) / 4) = self^(2^29)`.
+    /// Which of the two roots is returned is unspecified.
+    pub fn sqrt(&self) -> Option<BaseField> {
+        let root = self.exp_power_of_2(29);
+        (root.square() == *self).then_some(root)
     }
 
-    /// Computes `v^(2*n)`.
-    fn sqn<const N: usize>(mut v: BaseField) -> BaseField {
-        for _ in 0..N {
-            v = v.square();
+    /// Legendre symbol via Euler's criterion `self^((p - 1) / 2)`: `0` for
+    /// zero, `1` for non-zero squares and `-1` otherwise.
+    pub fn legendre_symbol(&self) -> i32 {
+        match self.pow(((PRIME - 1) / 2) as u128).0 {
+            0 => 0,
+            1 => 1,
+            _ => -1,
         }
-        v
+    }
+
+    /// Whether `self` has a square root in the field; zero counts as a square.
+    pub fn is_square(&self) -> bool {
+        self.legendre_symbol() != -1
+    }
+
+    /// Inverts all of `values` at the cost of one inversion.
+    /// Panics if any element is zero.
+    pub fn batch_inverse(values: &[BaseField]) -> Vec<BaseField> {
+        batch_inverse(values)
+    }
+
+    pub fn batch_inverse_in_place(values: &mut [BaseField]) {
+        batch_inverse_in_place(values)
+    }
+
+    pub fn to_le_bytes(&self) -> [u8; 4] {
+        self.0.to_le_bytes()
+    }
+
+    /// Returns `None` unless `bytes` encode a value below `PRIME`.
+    pub fn from_le_bytes(bytes: [u8; 4]) -> Option<BaseField> {
+        let value = u32::from_le_bytes(bytes);
+        (value < PRIME).then_some(BaseField(value))
+    }
+
+    /// Reads `bytes` as a little-endian integer of any length and reduces it
+    /// mod p. With at least 8 bytes of uniform input the result is within
+    /// `2^-33` of uniform.
+    pub fn from_bytes_mod_order(bytes: &[u8]) -> BaseField {
+        bytes.iter().rev().fold(BaseField(0), |acc, &byte| {
+            BaseField::from_unreduced(((acc.0 as u64) << 8) | byte as u64)
+        })
+    }
+
+    /// Canonical textual form, e.g. `0x1234`.
+    pub fn to_hex(&self) -> String {
+        format!("{:#x}", self)
+    }
+
+    /// Reduces a value in `[0, 2 * PRIME)` to `[0, PRIME)` without branching.
+    /// If `val < PRIME` then `val - PRIME` wraps and has its top bit set,
+    /// which is turned into a mask selecting `val` instead.
+    const fn reduce_once(val: u32) -> BaseField {
+        let sub = val.wrapping_sub(PRIME);
+        let keep_val = ((sub as i32) >> 31) as u32;
+        BaseField((val & keep_val) | (sub & !keep_val))
+    }
+
+    /// Computes `v^(2^n)`.
+    fn sqn<const N: usize>(v: BaseField) -> BaseField {
+        v.exp_power_of_2(N)
+    }
+}
+
+impl fmt::Display for BaseField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl fmt::LowerHex for BaseField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.0, f)
+    }
+}
+
+impl From<u32> for BaseField {
+    fn from(value: u32) -> Self {
+        BaseField::new(value)
+    }
+}
+
+impl From<u64> for BaseField {
+    fn from(value: u64) -> Self {
+        BaseField::from_unreduced(value)
+    }
+}
+
+impl From<i32> for BaseField {
+    fn from(value: i32) -> Self {
+        BaseField::from_i32(value)
+    }
+}
+
+impl Field for BaseField {
+    fn zero() -> Self {
+        BaseField(0)
+    }
+
+    fn one() -> Self {
+        BaseField(1)
+    }
+
+    fn inverse(&self) -> Self {
+        BaseField::inverse(*self)
+    }
+
+    fn try_inverse(&self) -> Option<Self> {
+        BaseField::try_inverse(self)
+    }
+
+    fn pow(&self, exp: u128) -> Self {
+        BaseField::pow(self, exp)
+    }
+
+    fn square(self) -> Self {
+        BaseField::square(self)
     }
 }
 
@@ -52,7 +266,7 @@ impl Add for BaseField {
     type Output = BaseField;
 
     fn add(self, other: BaseField) -> BaseField {
-        BaseField((self.0 + other.0) % PRIME)
+        self.const_add(other)
     }
 }
 
@@ -66,7 +280,7 @@ impl Sub for BaseField {
     type Output = BaseField;
 
     fn sub(self, other: BaseField) -> BaseField {
-        BaseField((self.0 + PRIME - other.0) % PRIME)
+        self.const_sub(other)
     }
 }
 
@@ -80,7 +294,7 @@ impl Mul for BaseField {
     type Output = BaseField;
 
     fn mul(self, other: BaseField) -> BaseField {
-        BaseField(((self.0 as u64 * other.0 as u64) % PRIME as u64) as u32)
+        self.const_mul(other)
     }
 }
 
@@ -94,18 +308,138 @@ impl Neg for BaseField {
     type Output = BaseField;
 
     fn neg(self) -> BaseField {
-        BaseField(PRIME - self.0)
+        self.const_neg()
     }
 }
 
 impl std::ops::Div for BaseField {
     type Output = BaseField;
 
+    #[allow(clippy::suspicious_arithmetic_impl)]
     fn div(self, other: BaseField) -> BaseField {
         self * other.inverse()
     }
 }
 
+impl DivAssign for BaseField {
+    fn div_assign(&mut self, other: BaseField) {
+        *self = *self / other;
+    }
+}
+
+impl Sum for BaseField {
+    fn sum<I: Iterator<Item = BaseField>>(iter: I) -> BaseField {
+        iter.fold(BaseField(0), |acc, x| acc + x)
+    }
+}
+
+impl<'a> Sum<&'a BaseField> for BaseField {
+    fn sum<I: Iterator<Item = &'a BaseField>>(iter: I) -> BaseField {
+        iter.copied().sum()
+    }
+}
+
+impl Product for BaseField {
+    fn product<I: Iterator<Item = BaseField>>(iter: I) -> BaseField {
+        iter.fold(BaseField(1), |acc, x| acc * x)
+    }
+}
+
+impl<'a> Product<&'a BaseField> for BaseField {
+    fn product<I: Iterator<Item = &'a BaseField>>(iter: I) -> BaseField {
+        iter.copied().product()
+    }
+}
+
+impl FromStr for BaseField {
+    type Err = ParseFieldError;
+
+    /// Parses the canonical `0x`-prefixed hex form produced by `to_hex`:
+    /// lowercase digits and no leading zeros, so `s.parse()?.to_hex() == s`.
+    /// Values outside `[0, PRIME)` are rejected rather than reduced.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s.strip_prefix("0x").ok_or(ParseFieldError::MissingPrefix)?;
+        if digits.is_empty()
+            || !digits
+                .bytes()
+                .all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+            || (digits.len() > 1 && digits.starts_with('0'))
+        {
+            return Err(ParseFieldError::InvalidDigit);
+        }
+        let value = u32::from_str_radix(digits, 16).map_err(|_| ParseFieldError::OutOfRange)?;
+        if value >= PRIME {
+            return Err(ParseFieldError::OutOfRange);
+        }
+        Ok(BaseField(value))
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl num_traits::Zero for BaseField {
+    fn zero() -> Self {
+        BaseField(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == BaseField(0)
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl num_traits::One for BaseField {
+    fn one() -> Self {
+        BaseField(1)
+    }
+}
+
+/// Panics on zero, like `inverse`.
+#[cfg(feature = "num-traits")]
+impl num_traits::Inv for BaseField {
+    type Output = BaseField;
+
+    fn inv(self) -> BaseField {
+        self.inverse()
+    }
+}
+
+/// Uniform over `[0, PRIME)`: draws 31 bits and rejects the single
+/// out-of-range value `PRIME`.
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<BaseField> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> BaseField {
+        loop {
+            let value = rng.next_u32() & PRIME;
+            if value != PRIME {
+                return BaseField(value);
+            }
+        }
+    }
+}
+
+/// Serialized as the canonical `u32` representative.
+#[cfg(feature = "serde")]
+impl serde::Serialize for BaseField {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(self.0)
+    }
+}
+
+/// Rejects values `>= PRIME` instead of reducing them, so every element has
+/// exactly one encoding.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for BaseField {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = u32::deserialize(deserializer)?;
+        if value >= PRIME {
+            return Err(serde::de::Error::custom(format!(
+                "{value} is not a canonical field element"
+            )));
+        }
+        Ok(BaseField(value))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -152,6 +486,79 @@ mod tests {
             );
             assert_eq!(BaseField::new(neg_p(x)), -BaseField::new(x));
         }
+        assert_eq!(-BaseField::new(0), BaseField(0));
+    }
+
+    #[test]
+    fn test_unreduced_ops() {
+        assert_eq!(
+            BaseField::from_unreduced(u64::MAX),
+            BaseField::new((u64::MAX % PRIME as u64) as u32)
+        );
+        assert_eq!(BaseField::from_unreduced(PRIME as u64), BaseField::new(0));
+
+        let mut rng = SmallRng::seed_from_u64(0);
+        for _ in 0..1000 {
+            let xs: [BaseField; 4] = std::array::from_fn(|_| BaseField::new(rng.gen::<u32>()));
+            let ys: [BaseField; 4] = std::array::from_fn(|_| BaseField::new(rng.gen::<u32>()));
+            let mut acc = 0u64;
+            let mut expected = BaseField::new(0);
+            for (x, y) in xs.iter().zip(ys.iter()) {
+                acc += x.mul_unreduced(*y);
+                expected += *x * *y;
+            }
+            assert_eq!(BaseField::from_unreduced(acc), expected);
+
+            let val = rng.gen::<u64>();
+            let partial = BaseField::partial_reduce(val);
+            assert!(partial < 1 << 34);
+            assert_eq!(partial % PRIME as u64, val % PRIME as u64);
+        }
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(BaseField::from(PRIME + 3), BaseField::new(3));
+        assert_eq!(
+            BaseField::from(u64::MAX),
+            BaseField::from_unreduced(u64::MAX)
+        );
+        assert_eq!(BaseField::from(5i32), BaseField::new(5));
+        assert_eq!(BaseField::from(-5i32), BaseField::new(PRIME - 5));
+        assert_eq!(BaseField::from(0i32), BaseField::new(0));
+        assert_eq!(BaseField::from(i32::MAX), BaseField::new(0));
+        // |i32::MIN| = 2^31 = PRIME + 1.
+        assert_eq!(BaseField::from(i32::MIN), BaseField::new(PRIME - 1));
+    }
+
+    #[test]
+    fn test_const_ops() {
+        const X: BaseField = BaseField::new(PRIME + 5);
+        const Y: BaseField = BaseField::new(PRIME - 3);
+        const SUM: BaseField = X.const_add(Y);
+        const DIFF: BaseField = X.const_sub(Y);
+        const PROD: BaseField = X.const_mul(Y);
+        const NEG: BaseField = Y.const_neg();
+        assert_eq!(X, BaseField::new(5));
+        assert_eq!(SUM, X + Y);
+        assert_eq!(DIFF, X - Y);
+        assert_eq!(PROD, X * Y);
+        assert_eq!(NEG, -Y);
+        const REDUCED: BaseField = BaseField::from_unreduced(u64::MAX);
+        assert_eq!(REDUCED, BaseField::from_unreduced(u64::MAX));
+    }
+
+    #[test]
+    fn test_reduction_edge_cases() {
+        let zero = BaseField::new(0);
+        let max = BaseField::new(PRIME - 1);
+        assert_eq!(max + BaseField::new(1), zero);
+        assert_eq!(max + max, BaseField::new(PRIME - 2));
+        assert_eq!(zero - BaseField::new(1), max);
+        assert_eq!(zero - max, BaseField::new(1));
+        assert_eq!(max * max, BaseField::new(1));
+        assert_eq!(max * zero, zero);
+        assert_eq!(-max, BaseField::new(1));
     }
 
     #[test]
@@ -176,6 +583,9 @@ mod tests {
             let bx = BaseField::new(x);
             let by = BaseField::new(y);
             assert_eq!(bx / by, bx * by.inverse());
+            let mut acc = bx;
+            acc /= by;
+            assert_eq!(acc, bx / by);
         }
     }
 
@@ -194,6 +604,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_small_pow_ops() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        for _ in 0..1000 {
+            let bx = BaseField::new(rng.gen::<u32>());
+            assert_eq!(bx.cube(), bx.pow(3));
+            for log in 0..8 {
+                assert_eq!(bx.exp_power_of_2(log), bx.pow(1 << log));
+                assert_eq!(bx.pow2k(log), bx.exp_power_of_2(log));
+            }
+        }
+    }
+
     #[test]
     fn test_inverse() {
         let mut rng = SmallRng::seed_from_u64(0);
@@ -201,6 +624,171 @@ mod tests {
             let x: u32 = rng.gen::<u32>() % (PRIME - 1) + 1;
             let bx = BaseField::new(x);
             assert_eq!(bx * bx.inverse(), BaseField::new(1));
+            assert_eq!(bx.try_inverse(), Some(bx.inverse()));
+        }
+        assert_eq!(BaseField::new(0).try_inverse(), None);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_random_sampling() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let samples: Vec<BaseField> = (0..1000).map(|_| rng.gen()).collect();
+        assert!(samples.iter().all(|x| x.0 < PRIME));
+        // The top bit of the 31 is set for roughly half of the samples.
+        let high = samples.iter().filter(|x| x.0 >= 1 << 30).count();
+        assert!((400..600).contains(&high));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let x = BaseField::new(PRIME - 1);
+        let json = serde_json::to_string(&x).unwrap();
+        assert_eq!(json, "2147483646");
+        assert_eq!(serde_json::from_str::<BaseField>(&json).unwrap(), x);
+        assert!(serde_json::from_str::<BaseField>("2147483647").is_err());
+    }
+
+    #[test]
+    fn test_sqrt() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        for _ in 0..1000 {
+            let x = BaseField::new(rng.gen::<u32>());
+            let root = x.square().sqrt().unwrap();
+            assert!(root == x || root == -x);
+        }
+        assert_eq!(BaseField::new(0).sqrt(), Some(BaseField::new(0)));
+        // -1 is not a square since p = 3 mod 4.
+        assert_eq!(BaseField::new(PRIME - 1).sqrt(), None);
+    }
+
+    #[test]
+    fn test_is_square() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        for _ in 0..1000 {
+            let x = BaseField::new(rng.gen::<u32>() % (PRIME - 1) + 1);
+            assert_eq!(x.square().legendre_symbol(), 1);
+            assert_eq!(x.is_square(), x.sqrt().is_some());
+            assert_eq!((-x.square()).legendre_symbol(), -1);
+        }
+        assert_eq!(BaseField::new(0).legendre_symbol(), 0);
+        assert!(BaseField::new(0).is_square());
+    }
+
+    #[test]
+    fn test_batch_inverse() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let values: Vec<BaseField> = (0..100)
+            .map(|_| BaseField::new(rng.gen::<u32>() % (PRIME - 1) + 1))
+            .collect();
+        let expected: Vec<BaseField> = values.iter().map(|v| v.inverse()).collect();
+        assert_eq!(BaseField::batch_inverse(&values), expected);
+
+        let mut in_place = values.clone();
+        BaseField::batch_inverse_in_place(&mut in_place);
+        assert_eq!(in_place, expected);
+
+        assert!(BaseField::batch_inverse(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_bytes() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        for _ in 0..1000 {
+            let x = BaseField::new(rng.gen::<u32>());
+            assert_eq!(BaseField::from_le_bytes(x.to_le_bytes()), Some(x));
+        }
+        assert_eq!(BaseField::new(0x01020304).to_le_bytes(), [4, 3, 2, 1]);
+        assert_eq!(BaseField::from_le_bytes([0xff, 0xff, 0xff, 0x7f]), None);
+
+        assert_eq!(BaseField::from_bytes_mod_order(&[]), BaseField::new(0));
+        assert_eq!(
+            BaseField::from_bytes_mod_order(&[4, 3, 2, 1]),
+            BaseField::new(0x01020304)
+        );
+        for _ in 0..100 {
+            let bytes: [u8; 16] = rng.gen();
+            let expected = (u128::from_le_bytes(bytes) % PRIME as u128) as u32;
+            assert_eq!(
+                BaseField::from_bytes_mod_order(&bytes),
+                BaseField::new(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn test_sum_product() {
+        let values: Vec<BaseField> = (1..=10).map(BaseField::new).collect();
+        assert_eq!(values.iter().sum::<BaseField>(), BaseField::new(55));
+        assert_eq!(
+            values.iter().copied().sum::<BaseField>(),
+            BaseField::new(55)
+        );
+        assert_eq!(
+            values.iter().product::<BaseField>(),
+            BaseField::new(3628800)
+        );
+        assert_eq!(
+            values.into_iter().product::<BaseField>(),
+            BaseField::new(3628800)
+        );
+        assert_eq!(
+            std::iter::empty::<BaseField>().sum::<BaseField>(),
+            BaseField::new(0)
+        );
+        assert_eq!(
+            std::iter::empty::<BaseField>().product::<BaseField>(),
+            BaseField::new(1)
+        );
+    }
+
+    #[test]
+    fn test_formatting() {
+        let x = BaseField::new(PRIME - 1);
+        assert_eq!(format!("{x}"), "2147483646");
+        assert_eq!(format!("{x:x}"), "7ffffffe");
+        assert_eq!(format!("{x:#x}"), "0x7ffffffe");
+        assert_eq!(format!("{:>4}", BaseField::new(7)), "   7");
+    }
+
+    #[test]
+    fn test_hex_round_trip() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        for _ in 0..1000 {
+            let x = BaseField::new(rng.gen::<u32>());
+            assert_eq!(x.to_hex().parse::<BaseField>(), Ok(x));
+        }
+        assert_eq!(BaseField::new(0x1234).to_hex(), "0x1234");
+        assert_eq!(BaseField::new(0).to_hex(), "0x0");
+    }
+
+    #[test]
+    fn test_hex_parse_errors() {
+        assert_eq!(
+            "1234".parse::<BaseField>(),
+            Err(ParseFieldError::MissingPrefix)
+        );
+        assert_eq!(
+            "0x".parse::<BaseField>(),
+            Err(ParseFieldError::InvalidDigit)
+        );
+        assert_eq!(
+            "0x+1".parse::<BaseField>(),
+            Err(ParseFieldError::InvalidDigit)
+        );
+        for s in ["0xABC", "0x00000001", "0x0000000000000000001", "0x00"] {
+            assert_eq!(s.parse::<BaseField>(), Err(ParseFieldError::InvalidDigit));
         }
+        assert_eq!("0x0".parse::<BaseField>(), Ok(BaseField::new(0)));
+        assert_eq!("0xabc".parse::<BaseField>(), Ok(BaseField::new(0xabc)));
+        assert_eq!(
+            "0x7fffffff".parse::<BaseField>(),
+            Err(ParseFieldError::OutOfRange)
+        );
+        assert_eq!(
+            "0x100000000".parse::<BaseField>(),
+            Err(ParseFieldError::OutOfRange)
+        );
     }
 }
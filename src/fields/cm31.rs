@@ -0,0 +1,356 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use super::{basefield::BaseField, Field};
+
+/// Complex extension field of `BaseField`.
+/// (a, b) = a + bi
+/// i^2 = -1.
+///
+/// Ordered lexicographically by `(a, b)`.
+#[derive(Clone, Debug, PartialEq, Eq, Copy, Hash, PartialOrd, Ord, Default)]
+pub struct CM31(pub BaseField, pub BaseField);
+
+impl CM31 {
+    pub const fn new(a: u32, b: u32) -> Self {
+        CM31(BaseField::new(a), BaseField::new(b))
+    }
+
+    pub fn pow(&self, exp: u128) -> Self {
+        let mut res = CM31::new(1, 0);
+        let mut base = *self;
+        let mut exp = exp;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                res *= base;
+            }
+            base = base.square();
+            exp >>= 1;
+        }
+        res
+    }
+
+    pub fn square(self) -> Self {
+        self * self
+    }
+
+    /// `a + bi -> -b + ai`.
+    pub fn mul_by_i(self) -> Self {
+        CM31(-self.1, self.0)
+    }
+
+    /// `a + bi -> a - bi`.
+    pub fn conjugate(self) -> Self {
+        CM31(self.0, -self.1)
+    }
+
+    /// `N(a + bi) = (a + bi)(a - bi) = a^2 + b^2`.
+    pub fn norm(&self) -> BaseField {
+        self.0.square() + self.1.square()
+    }
+
+    /// `Tr(a + bi) = (a + bi) + (a - bi) = 2a`.
+    pub fn trace(&self) -> BaseField {
+        self.0 + self.0
+    }
+
+    /// Panics if `self` is zero; use `try_inverse` on untrusted input.
+    pub fn inverse(&self) -> Self {
+        self.try_inverse().expect("0 has no inverse")
+    }
+
+    pub fn try_inverse(&self) -> Option<Self> {
+        // 1 / (a + bi) = (a - bi) / (a^2 + b^2). The denominator only
+        // vanishes at zero since -1 is not a square mod p.
+        Some(self.conjugate() * self.norm().try_inverse()?)
+    }
+}
+
+impl fmt::Display for CM31 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} + {}i", self.0, self.1)
+    }
+}
+
+impl From<BaseField> for CM31 {
+    fn from(value: BaseField) -> Self {
+        CM31(value, BaseField(0))
+    }
+}
+
+impl Field for CM31 {
+    fn zero() -> Self {
+        CM31::new(0, 0)
+    }
+
+    fn one() -> Self {
+        CM31::new(1, 0)
+    }
+
+    fn inverse(&self) -> Self {
+        CM31::inverse(self)
+    }
+
+    fn try_inverse(&self) -> Option<Self> {
+        CM31::try_inverse(self)
+    }
+
+    fn pow(&self, exp: u128) -> Self {
+        CM31::pow(self, exp)
+    }
+
+    fn square(self) -> Self {
+        CM31::square(self)
+    }
+}
+
+impl Add for CM31 {
+    type Output = CM31;
+
+    fn add(self, other: CM31) -> CM31 {
+        CM31(self.0 + other.0, self.1 + other.1)
+    }
+}
+
+impl AddAssign for CM31 {
+    fn add_assign(&mut self, other: CM31) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for CM31 {
+    type Output = CM31;
+
+    fn sub(self, other: CM31) -> CM31 {
+        CM31(self.0 - other.0, self.1 - other.1)
+    }
+}
+
+impl SubAssign for CM31 {
+    fn sub_assign(&mut self, other: CM31) {
+        *self = *self - other;
+    }
+}
+
+impl Mul for CM31 {
+    type Output = CM31;
+
+    fn mul(self, other: CM31) -> CM31 {
+        // (a + bi)(c + di) = (ac - bd) + (ad + bc)i.
+        CM31(
+            self.0 * other.0 - self.1 * other.1,
+            self.0 * other.1 + self.1 * other.0,
+        )
+    }
+}
+
+impl MulAssign for CM31 {
+    fn mul_assign(&mut self, other: CM31) {
+        *self = *self * other;
+    }
+}
+
+impl Neg for CM31 {
+    type Output = CM31;
+
+    fn neg(self) -> CM31 {
+        CM31(-self.0, -self.1)
+    }
+}
+
+impl Div for CM31 {
+    type Output = CM31;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: CM31) -> CM31 {
+        self * other.inverse()
+    }
+}
+
+impl DivAssign for CM31 {
+    fn div_assign(&mut self, other: CM31) {
+        *self = *self / other;
+    }
+}
+
+impl Add<BaseField> for CM31 {
+    type Output = CM31;
+
+    fn add(self, other: BaseField) -> CM31 {
+        CM31(self.0 + other, self.1)
+    }
+}
+
+impl AddAssign<BaseField> for CM31 {
+    fn add_assign(&mut self, other: BaseField) {
+        *self = *self + other;
+    }
+}
+
+impl Sub<BaseField> for CM31 {
+    type Output = CM31;
+
+    fn sub(self, other: BaseField) -> CM31 {
+        CM31(self.0 - other, self.1)
+    }
+}
+
+impl SubAssign<BaseField> for CM31 {
+    fn sub_assign(&mut self, other: BaseField) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<BaseField> for CM31 {
+    type Output = CM31;
+
+    fn mul(self, other: BaseField) -> CM31 {
+        CM31(self.0 * other, self.1 * other)
+    }
+}
+
+impl MulAssign<BaseField> for CM31 {
+    fn mul_assign(&mut self, other: BaseField) {
+        *self = *self * other;
+    }
+}
+
+impl Div<BaseField> for CM31 {
+    type Output = CM31;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: BaseField) -> CM31 {
+        self * other.inverse()
+    }
+}
+
+impl DivAssign<BaseField> for CM31 {
+    fn div_assign(&mut self, other: BaseField) {
+        *self = *self / other;
+    }
+}
+
+impl Add<CM31> for BaseField {
+    type Output = CM31;
+
+    fn add(self, other: CM31) -> CM31 {
+        other + self
+    }
+}
+
+impl Sub<CM31> for BaseField {
+    type Output = CM31;
+
+    fn sub(self, other: CM31) -> CM31 {
+        -other + self
+    }
+}
+
+impl Mul<CM31> for BaseField {
+    type Output = CM31;
+
+    fn mul(self, other: CM31) -> CM31 {
+        other * self
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<CM31> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> CM31 {
+        CM31(rng.gen(), rng.gen())
+    }
+}
+
+/// Serialized as the pair `(a, b)` of canonical limbs.
+#[cfg(feature = "serde")]
+impl serde::Serialize for CM31 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.0, self.1).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CM31 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (a, b) = <(BaseField, BaseField)>::deserialize(deserializer)?;
+        Ok(CM31(a, b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fields::basefield::PRIME;
+
+    #[test]
+    fn test_ops() {
+        let cm0 = CM31::new(1, 2);
+        let cm1 = CM31::new(4, 5);
+        let m = BaseField::new(8);
+        let cm = CM31::from(m);
+
+        assert_eq!(cm0 + cm1, CM31::new(5, 7));
+        assert_eq!(cm1 + m, cm1 + cm);
+        assert_eq!(cm0 * cm1, CM31::new(PRIME - 6, 13));
+        assert_eq!(cm1 * m, cm1 * cm);
+        assert_eq!(-cm0, CM31::new(PRIME - 1, PRIME - 2));
+        assert_eq!(cm0 - cm1, CM31::new(PRIME - 3, PRIME - 3));
+        assert_eq!(cm1 - m, cm1 - cm);
+        assert_eq!((cm0 * cm1) / cm1, cm0);
+        assert_eq!(cm1 / m, cm1 / cm);
+        assert_eq!(m + cm1, cm + cm1);
+        assert_eq!(m - cm1, cm - cm1);
+        assert_eq!(m * cm1, cm * cm1);
+        assert_eq!(CM31::new(0, 1).square(), CM31::new(PRIME - 1, 0));
+        assert_eq!(cm0.mul_by_i(), cm0 * CM31::new(0, 1));
+    }
+
+    #[test]
+    fn test_assign_ops() {
+        let cm0 = CM31::new(1, 2);
+        let cm1 = CM31::new(4, 5);
+        let m = BaseField::new(8);
+
+        let mut acc = cm0;
+        acc /= cm1;
+        assert_eq!(acc, cm0 / cm1);
+
+        let mut acc = cm0;
+        acc += m;
+        acc *= m;
+        acc -= m;
+        acc /= m;
+        assert_eq!(acc, ((cm0 + m) * m - m) / m);
+    }
+
+    #[test]
+    fn test_norm_trace() {
+        let cm = CM31::new(3, PRIME - 7);
+        assert_eq!(CM31(cm.norm(), BaseField(0)), cm * cm.conjugate());
+        assert_eq!(CM31(cm.trace(), BaseField(0)), cm + cm.conjugate());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(CM31::new(1, PRIME - 1).to_string(), "1 + 2147483646i");
+    }
+
+    #[test]
+    fn test_inverse() {
+        for cm in [CM31::new(1, 0), CM31::new(0, 1), CM31::new(3, PRIME - 7)] {
+            assert_eq!(cm * cm.inverse(), CM31::new(1, 0));
+        }
+        assert_eq!(CM31::new(0, 0).try_inverse(), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let cm = CM31::new(5, 6);
+        let json = serde_json::to_string(&cm).unwrap();
+        assert_eq!(json, "[5,6]");
+        assert_eq!(serde_json::from_str::<CM31>(&json).unwrap(), cm);
+        assert!(serde_json::from_str::<CM31>("[5,2147483647]").is_err());
+        assert!(serde_json::from_str::<CM31>("[5]").is_err());
+    }
+}
@@ -1,30 +1,46 @@
 use std::{
-    array,
-    ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign},
+    fmt,
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
 };
 
-use super::basefield::BaseField;
+use super::{
+    basefield::BaseField, batch_inverse, batch_inverse_in_place, cm31::CM31, Field, ParseFieldError,
+};
 
-/// Quadratic extension field of `BaseField`.
-/// (a,b,c,d) = (a + bi) + (c + di)j
-/// i^2 = -1, j^2 = 2 + i.
+/// Quadratic extension field of `CM31`.
+/// (a, b) = a + bj, with a, b in CM31
+/// j^2 = R = 2 + i.
+///
+/// In base field limbs: (a, b, c, d) = (a + bi) + (c + di)j.
+///
+/// Ordered lexicographically by the limbs `[a, b, c, d]`.
+#[derive(Clone, Debug, PartialEq, Eq, Copy, Hash, PartialOrd, Ord, Default)]
+pub struct ExtensionField(pub CM31, pub CM31);
 
-#[derive(Clone, Debug, PartialEq, Eq, Copy)]
-pub struct ExtensionField(pub [BaseField; 4]);
+/// `j^(p - 1) = R^((p - 1) / 2)`, so that `j^p = FROBENIUS_J * j`.
+const FROBENIUS_J: CM31 = CM31::new(21189756, 42379512);
 
 impl ExtensionField {
-    pub fn new(a: u32, b: u32, c: u32, d: u32) -> Self {
-        ExtensionField([
-            BaseField::new(a),
-            BaseField::new(b),
-            BaseField::new(c),
-            BaseField::new(d),
-        ])
+    pub const fn new(a: u32, b: u32, c: u32, d: u32) -> Self {
+        ExtensionField(CM31::new(a, b), CM31::new(c, d))
+    }
+
+    /// Builds `(a + bi) + (c + di)j` from `[a, b, c, d]`.
+    pub const fn from_limbs(limbs: [BaseField; 4]) -> Self {
+        let [a, b, c, d] = limbs;
+        ExtensionField(CM31(a, b), CM31(c, d))
+    }
+
+    /// Returns `[a, b, c, d]` for `(a + bi) + (c + di)j`.
+    pub const fn to_limbs(&self) -> [BaseField; 4] {
+        [self.0 .0, self.0 .1, self.1 .0, self.1 .1]
     }
 
     pub fn pow(&self, exp: u128) -> Self {
         let mut res = ExtensionField::new(1, 0, 0, 0);
-        let mut base = self.clone();
+        let mut base = *self;
         let mut exp = exp;
         while exp > 0 {
             if exp & 1 == 1 {
@@ -40,41 +56,196 @@ impl ExtensionField {
         self * self
     }
 
-    fn inverse(&self) -> Self {
-        assert!(*self != ExtensionField::new(0, 0, 0, 0));
-        let b2 = Self::square_complex((self.0[2], self.0[3]));
-        let ib2 = (-b2.1, b2.0);
-        let a2 = Self::square_complex((self.0[0], self.0[1]));
-        let denom = (
-            a2.0 - (b2.0 + b2.0 + ib2.0),
-            a2.1 - (b2.1 + b2.1 + ib2.1),
-        );
-        let denom_inverse = Self::inverse_complex(denom.0, denom.1);
-        let (a, b) = Self::mul_complex((self.0[0], self.0[1]), denom_inverse);
-        let (c, d) = Self::mul_complex((-self.0[2], -self.0[3]), denom_inverse);
-        Self([a, b, c, d])
+    pub fn cube(self) -> Self {
+        self.square() * self
+    }
+
+    /// Computes `self^(2^log)` by repeated squaring.
+    pub fn exp_power_of_2(self, log: usize) -> Self {
+        let mut res = self;
+        for _ in 0..log {
+            res = res.square();
+        }
+        res
+    }
+
+    /// Same as `exp_power_of_2`: `self^(2^k)`.
+    pub fn pow2k(self, k: usize) -> Self {
+        self.exp_power_of_2(k)
+    }
+
+    /// Multiplies by `i`: `a + bj -> ai + (bi)j`.
+    pub fn mul_by_i(self) -> Self {
+        ExtensionField(self.0.mul_by_i(), self.1.mul_by_i())
+    }
+
+    /// Multiplies by `j`: `a + bj -> bR + aj`.
+    pub fn mul_by_j(self) -> Self {
+        ExtensionField(Self::mul_by_nonresidue(self.1), self.0)
+    }
+
+    /// Multiplies every limb by a base field scalar.
+    pub fn mul_by_base_scalar(self, scalar: BaseField) -> Self {
+        ExtensionField(self.0 * scalar, self.1 * scalar)
+    }
+
+    /// Computes `self^(p^power)`. On `a + bj` the p-power map conjugates
+    /// both CM31 coefficients (`i^p = -i`) and scales `j` by `FROBENIUS_J`.
+    /// It has order 4, so only `power % 4` matters.
+    pub fn frobenius(&self, power: usize) -> Self {
+        let mut res = *self;
+        for _ in 0..power % 4 {
+            res = ExtensionField(res.0.conjugate(), res.1.conjugate() * FROBENIUS_J);
+        }
+        res
+    }
+
+    /// Relative norm to CM31: `(a + bj)(a - bj) = a^2 - R b^2`.
+    pub fn norm_cm31(&self) -> CM31 {
+        self.0.square() - Self::mul_by_nonresidue(self.1.square())
+    }
+
+    /// Relative trace to CM31: `(a + bj) + (a - bj) = 2a`.
+    pub fn trace_cm31(&self) -> CM31 {
+        self.0 + self.0
+    }
+
+    /// Absolute norm, the product of all four Frobenius conjugates.
+    pub fn norm(&self) -> BaseField {
+        self.norm_cm31().norm()
     }
 
-    fn inverse_complex(a: BaseField, b: BaseField) -> (BaseField, BaseField) {
-        assert!(a != BaseField(0) || b != BaseField(0), "0 has no inverse");
-        // 1 / (a + bi) = (a - bi) / (a^2 + b^2).
-        Self::mul_complex_base(a, -b, (a.square() + b.square()).inverse())
+    /// Absolute trace, the sum of all four Frobenius conjugates.
+    pub fn trace(&self) -> BaseField {
+        self.trace_cm31().trace()
     }
 
-    fn mul_complex_base(a: BaseField, b: BaseField, c: BaseField) -> (BaseField, BaseField) {
-        (a * c, b * c)
+    /// Inverts all of `values` at the cost of one inversion.
+    /// Panics if any element is zero.
+    pub fn batch_inverse(values: &[ExtensionField]) -> Vec<ExtensionField> {
+        batch_inverse(values)
     }
 
-    fn mul_complex(a: (BaseField, BaseField), b: (BaseField, BaseField)) -> (BaseField, BaseField) {
-        let (a0, a1) = a;
-        let (b0, b1) = b;
-        let c0 = a0 * b0 - a1 * b1;
-        let c1 = a0 * b1 + a1 * b0;
-        (c0, c1)
+    pub fn batch_inverse_in_place(values: &mut [ExtensionField]) {
+        batch_inverse_in_place(values)
     }
 
-    fn square_complex(a: (BaseField, BaseField)) -> (BaseField, BaseField) {
-        Self::mul_complex(a, a)
+    /// Random linear combination `sum(values[i] * alpha^(n - 1 - i))` by
+    /// Horner's rule, so the first value gets the highest power of `alpha`.
+    /// Returns zero for an empty slice.
+    pub fn combine(values: &[ExtensionField], alpha: ExtensionField) -> ExtensionField {
+        values
+            .iter()
+            .fold(ExtensionField::zero(), |acc, &v| acc * alpha + v)
+    }
+
+    /// The four limbs of `to_limbs`, each as 4 little-endian bytes.
+    pub fn to_le_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        for (chunk, limb) in bytes.chunks_exact_mut(4).zip(self.to_limbs()) {
+            chunk.copy_from_slice(&limb.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Returns `None` unless every limb is below `PRIME`.
+    pub fn from_le_bytes(bytes: [u8; 16]) -> Option<ExtensionField> {
+        let mut limbs = [BaseField(0); 4];
+        for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(4)) {
+            *limb = BaseField::from_le_bytes(chunk.try_into().unwrap())?;
+        }
+        Some(ExtensionField::from_limbs(limbs))
+    }
+
+    /// Splits `bytes` into four consecutive chunks and reduces each with
+    /// `BaseField::from_bytes_mod_order`, e.g. 8 bytes per limb for a 32-byte
+    /// digest. Accepts any length; the first `len % 4` chunks get one extra
+    /// byte. Uniform input of at least 32 bytes gives near-uniform limbs.
+    pub fn from_bytes_mod_order(bytes: &[u8]) -> ExtensionField {
+        let (chunk_len, extra) = (bytes.len() / 4, bytes.len() % 4);
+        ExtensionField::from_limbs(std::array::from_fn(|i| {
+            let start = i * chunk_len + i.min(extra);
+            let end = start + chunk_len + usize::from(i < extra);
+            BaseField::from_bytes_mod_order(&bytes[start..end])
+        }))
+    }
+
+    /// Canonical textual form `0xa+0xbi+0xcj+0xdij`, matching the
+    /// `(a + bi) + (c + di)j` layout of the limbs.
+    pub fn to_hex(&self) -> String {
+        let [a, b, c, d] = self.to_limbs();
+        format!(
+            "{}+{}i+{}j+{}ij",
+            a.to_hex(),
+            b.to_hex(),
+            c.to_hex(),
+            d.to_hex()
+        )
+    }
+
+    /// Panics if `self` is zero; use `try_inverse` on untrusted input.
+    pub fn inverse(&self) -> Self {
+        self.try_inverse().expect("0 has no inverse")
+    }
+
+    pub fn try_inverse(&self) -> Option<Self> {
+        // 1 / (a + bj) = (a - bj) / (a^2 - R b^2). The denominator only
+        // vanishes at zero since R is not a square in CM31.
+        let denom_inverse = self.norm_cm31().try_inverse()?;
+        Some(ExtensionField(
+            self.0 * denom_inverse,
+            -self.1 * denom_inverse,
+        ))
+    }
+
+    /// `(x + yi)(2 + i) = (2x - y) + (x + 2y)i`, without a full CM31 product.
+    fn mul_by_nonresidue(v: CM31) -> CM31 {
+        let CM31(x, y) = v;
+        CM31(x + x - y, x + y + y)
+    }
+}
+
+impl fmt::Display for ExtensionField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}) + ({})j", self.0, self.1)
+    }
+}
+
+impl From<BaseField> for ExtensionField {
+    fn from(value: BaseField) -> Self {
+        ExtensionField(CM31::from(value), CM31::new(0, 0))
+    }
+}
+
+impl From<CM31> for ExtensionField {
+    fn from(value: CM31) -> Self {
+        ExtensionField(value, CM31::new(0, 0))
+    }
+}
+
+impl Field for ExtensionField {
+    fn zero() -> Self {
+        ExtensionField::new(0, 0, 0, 0)
+    }
+
+    fn one() -> Self {
+        ExtensionField::new(1, 0, 0, 0)
+    }
+
+    fn inverse(&self) -> Self {
+        ExtensionField::inverse(self)
+    }
+
+    fn try_inverse(&self) -> Option<Self> {
+        ExtensionField::try_inverse(self)
+    }
+
+    fn pow(&self, exp: u128) -> Self {
+        ExtensionField::pow(self, exp)
+    }
+
+    fn square(self) -> Self {
+        ExtensionField::square(self)
     }
 }
 
@@ -82,7 +253,7 @@ impl Add for ExtensionField {
     type Output = ExtensionField;
 
     fn add(self, other: ExtensionField) -> ExtensionField {
-        ExtensionField(array::from_fn(|i| self.0[i] + other.0[i]))
+        ExtensionField(self.0 + other.0, self.1 + other.1)
     }
 }
 
@@ -96,7 +267,7 @@ impl Sub for ExtensionField {
     type Output = ExtensionField;
 
     fn sub(self, other: ExtensionField) -> ExtensionField {
-        ExtensionField(array::from_fn(|i| self.0[i] - other.0[i]))
+        ExtensionField(self.0 - other.0, self.1 - other.1)
     }
 }
 
@@ -110,16 +281,12 @@ impl Mul for ExtensionField {
     type Output = ExtensionField;
 
     fn mul(self, other: ExtensionField) -> ExtensionField {
-        let (a0, b0) = Self::mul_complex((self.0[0], self.0[1]), (other.0[0], other.0[1]));
-        let (a1, b1) = Self::mul_complex(
-            (BaseField(2), BaseField(1)),
-            Self::mul_complex((self.0[2], self.0[3]), (other.0[2], other.0[3])),
-        );
-        let (a, b) = (a0 + a1, b0 + b1);
-        let (c0, d0) = Self::mul_complex((self.0[0], self.0[1]), (other.0[2], other.0[3]));
-        let (c1, d1) = Self::mul_complex((self.0[2], self.0[3]), (other.0[0], other.0[1]));
-        let (c, d) = (c0 + c1, d0 + d1);
-        ExtensionField([a, b, c, d])
+        // (a + bj)(c + dj) = (ac + R bd) + (ad + bc)j, with Karatsuba
+        // computing ad + bc = (a + b)(c + d) - ac - bd.
+        let ac = self.0 * other.0;
+        let bd = self.1 * other.1;
+        let cross = (self.0 + self.1) * (other.0 + other.1) - ac - bd;
+        ExtensionField(ac + Self::mul_by_nonresidue(bd), cross)
     }
 }
 
@@ -133,23 +300,81 @@ impl Neg for ExtensionField {
     type Output = ExtensionField;
 
     fn neg(self) -> ExtensionField {
-        ExtensionField(array::from_fn(|i| -self.0[i]))
+        ExtensionField(-self.0, -self.1)
     }
 }
 
 impl Div for ExtensionField {
     type Output = ExtensionField;
 
+    #[allow(clippy::suspicious_arithmetic_impl)]
     fn div(self, other: ExtensionField) -> ExtensionField {
         self * other.inverse()
     }
 }
 
+impl DivAssign for ExtensionField {
+    fn div_assign(&mut self, other: ExtensionField) {
+        *self = *self / other;
+    }
+}
+
+impl Sum for ExtensionField {
+    fn sum<I: Iterator<Item = ExtensionField>>(iter: I) -> ExtensionField {
+        iter.fold(ExtensionField::new(0, 0, 0, 0), |acc, x| acc + x)
+    }
+}
+
+impl<'a> Sum<&'a ExtensionField> for ExtensionField {
+    fn sum<I: Iterator<Item = &'a ExtensionField>>(iter: I) -> ExtensionField {
+        iter.copied().sum()
+    }
+}
+
+impl Product for ExtensionField {
+    fn product<I: Iterator<Item = ExtensionField>>(iter: I) -> ExtensionField {
+        iter.fold(ExtensionField::new(1, 0, 0, 0), |acc, x| acc * x)
+    }
+}
+
+impl<'a> Product<&'a ExtensionField> for ExtensionField {
+    fn product<I: Iterator<Item = &'a ExtensionField>>(iter: I) -> ExtensionField {
+        iter.copied().product()
+    }
+}
+
+impl FromStr for ExtensionField {
+    type Err = ParseFieldError;
+
+    /// Parses the canonical form produced by `to_hex`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split('+');
+        let mut limbs = [BaseField(0); 4];
+        for (limb, suffix) in limbs.iter_mut().zip(["", "i", "j", "ij"]) {
+            let part = parts.next().ok_or(ParseFieldError::InvalidFormat)?;
+            let digits = part
+                .strip_suffix(suffix)
+                .ok_or(ParseFieldError::InvalidFormat)?;
+            *limb = digits.parse()?;
+        }
+        if parts.next().is_some() {
+            return Err(ParseFieldError::InvalidFormat);
+        }
+        Ok(ExtensionField::from_limbs(limbs))
+    }
+}
+
 impl Add<BaseField> for ExtensionField {
     type Output = ExtensionField;
 
     fn add(self, other: BaseField) -> ExtensionField {
-        ExtensionField([self.0[0] + other, self.0[1], self.0[2], self.0[3]])
+        ExtensionField(self.0 + other, self.1)
+    }
+}
+
+impl AddAssign<BaseField> for ExtensionField {
+    fn add_assign(&mut self, other: BaseField) {
+        *self = *self + other;
     }
 }
 
@@ -157,7 +382,13 @@ impl Sub<BaseField> for ExtensionField {
     type Output = ExtensionField;
 
     fn sub(self, other: BaseField) -> ExtensionField {
-        ExtensionField([self.0[0] - other, self.0[1], self.0[2], self.0[3]])
+        ExtensionField(self.0 - other, self.1)
+    }
+}
+
+impl SubAssign<BaseField> for ExtensionField {
+    fn sub_assign(&mut self, other: BaseField) {
+        *self = *self - other;
     }
 }
 
@@ -165,25 +396,103 @@ impl Mul<BaseField> for ExtensionField {
     type Output = ExtensionField;
 
     fn mul(self, other: BaseField) -> ExtensionField {
-        ExtensionField([
-            self.0[0] * other,
-            self.0[1] * other,
-            self.0[2] * other,
-            self.0[3] * other,
-        ])
+        self.mul_by_base_scalar(other)
+    }
+}
+
+impl MulAssign<BaseField> for ExtensionField {
+    fn mul_assign(&mut self, other: BaseField) {
+        *self = *self * other;
     }
 }
 
 impl Div<BaseField> for ExtensionField {
     type Output = ExtensionField;
 
+    #[allow(clippy::suspicious_arithmetic_impl)]
     fn div(self, other: BaseField) -> ExtensionField {
-        ExtensionField([
-            self.0[0] / other,
-            self.0[1] / other,
-            self.0[2] / other,
-            self.0[3] / other,
-        ])
+        self.mul_by_base_scalar(other.inverse())
+    }
+}
+
+impl DivAssign<BaseField> for ExtensionField {
+    fn div_assign(&mut self, other: BaseField) {
+        *self = *self / other;
+    }
+}
+
+impl Add<ExtensionField> for BaseField {
+    type Output = ExtensionField;
+
+    fn add(self, other: ExtensionField) -> ExtensionField {
+        other + self
+    }
+}
+
+impl Sub<ExtensionField> for BaseField {
+    type Output = ExtensionField;
+
+    fn sub(self, other: ExtensionField) -> ExtensionField {
+        -other + self
+    }
+}
+
+impl Mul<ExtensionField> for BaseField {
+    type Output = ExtensionField;
+
+    fn mul(self, other: ExtensionField) -> ExtensionField {
+        other * self
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl num_traits::Zero for ExtensionField {
+    fn zero() -> Self {
+        ExtensionField::new(0, 0, 0, 0)
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == ExtensionField::new(0, 0, 0, 0)
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl num_traits::One for ExtensionField {
+    fn one() -> Self {
+        ExtensionField::new(1, 0, 0, 0)
+    }
+}
+
+/// Panics on zero, like `inverse`.
+#[cfg(feature = "num-traits")]
+impl num_traits::Inv for ExtensionField {
+    type Output = ExtensionField;
+
+    fn inv(self) -> ExtensionField {
+        self.inverse()
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<ExtensionField> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> ExtensionField {
+        ExtensionField(rng.gen(), rng.gen())
+    }
+}
+
+/// Serialized as the flat limb array `[a, b, c, d]`, matching `to_limbs`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for ExtensionField {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_limbs().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ExtensionField {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let limbs = <[BaseField; 4]>::deserialize(deserializer)?;
+        Ok(ExtensionField::from_limbs(limbs))
     }
 }
 
@@ -197,7 +506,7 @@ mod tests {
         let qm0 = ExtensionField::new(1, 2, 3, 4);
         let qm1 = ExtensionField::new(4, 5, 6, 7);
         let m = BaseField::new(8);
-        let qm = ExtensionField([m, BaseField(0), BaseField(0), BaseField(0)]);
+        let qm = ExtensionField::from(m);
         let qm0_x_qm1 = ExtensionField::new(PRIME - 71, 93, PRIME - 16, 50);
 
         assert_eq!(qm0 + qm1, ExtensionField::new(5, 7, 9, 11));
@@ -215,5 +524,249 @@ mod tests {
         assert_eq!(qm1 - m, qm1 - qm);
         assert_eq!(qm0_x_qm1 / qm1, ExtensionField::new(1, 2, 3, 4));
         assert_eq!(qm1 / m, qm1 / qm);
+        assert_eq!(m + qm1, qm + qm1);
+        assert_eq!(m - qm1, qm - qm1);
+        assert_eq!(m * qm1, qm * qm1);
+    }
+
+    #[test]
+    fn test_combine() {
+        let alpha = ExtensionField::new(3, 1, 4, 1);
+        let values = [
+            ExtensionField::new(1, 2, 3, 4),
+            ExtensionField::new(5, 6, 7, 8),
+            ExtensionField::new(PRIME - 1, 0, 2, 9),
+        ];
+        assert_eq!(
+            ExtensionField::combine(&values, alpha),
+            values[0] * alpha.square() + values[1] * alpha + values[2]
+        );
+        assert_eq!(ExtensionField::combine(&values[..1], alpha), values[0]);
+        assert_eq!(ExtensionField::combine(&[], alpha), ExtensionField::zero());
+    }
+
+    #[test]
+    fn test_assign_ops() {
+        let qm0 = ExtensionField::new(1, 2, 3, 4);
+        let qm1 = ExtensionField::new(4, 5, 6, 7);
+        let m = BaseField::new(8);
+
+        let mut acc = qm0;
+        acc /= qm1;
+        assert_eq!(acc, qm0 / qm1);
+
+        let mut acc = qm0;
+        acc += m;
+        acc *= m;
+        acc -= m;
+        acc /= m;
+        assert_eq!(acc, ((qm0 + m) * m - m) / m);
+
+        let mut acc = ExtensionField::zero();
+        for (col, coeff) in [(m, qm0), (m + m, qm1)] {
+            acc += coeff * col;
+        }
+        assert_eq!(acc, qm0 * m + qm1 * (m + m));
+    }
+
+    #[test]
+    fn test_limbs() {
+        let qm = ExtensionField::new(1, 2, 3, 4);
+        assert_eq!(qm, ExtensionField(CM31::new(1, 2), CM31::new(3, 4)));
+        assert_eq!(ExtensionField::from_limbs(qm.to_limbs()), qm);
+        let j = ExtensionField::new(0, 0, 1, 0);
+        assert_eq!(j.square(), ExtensionField(CM31::new(2, 1), CM31::new(0, 0)));
+    }
+
+    #[test]
+    fn test_small_pow_ops() {
+        let qm = ExtensionField::new(1, 2, 3, 4);
+        assert_eq!(qm.cube(), qm.pow(3));
+        for log in 0..8 {
+            assert_eq!(qm.exp_power_of_2(log), qm.pow(1 << log));
+            assert_eq!(qm.pow2k(log), qm.exp_power_of_2(log));
+        }
+    }
+
+    #[test]
+    fn test_frobenius() {
+        let qm0 = ExtensionField::new(1, 2, 3, 4);
+        let qm1 = ExtensionField::new(PRIME - 5, 6, 7, PRIME - 8);
+        assert_eq!(qm0.frobenius(1), qm0.pow(PRIME as u128));
+        assert_eq!(qm0.frobenius(2), qm0.pow((PRIME as u128).pow(2)));
+        assert_eq!(qm0.frobenius(3), qm0.frobenius(1).frobenius(2));
+        assert_eq!(qm0.frobenius(4), qm0);
+        assert_eq!(
+            (qm0 * qm1).frobenius(1),
+            qm0.frobenius(1) * qm1.frobenius(1)
+        );
+        let base = ExtensionField::new(9, 0, 0, 0);
+        assert_eq!(base.frobenius(1), base);
+    }
+
+    #[test]
+    fn test_norm_trace() {
+        let qm = ExtensionField::new(1, 2, 3, PRIME - 4);
+        let conjugates: Vec<ExtensionField> = (0..4).map(|k| qm.frobenius(k)).collect();
+        let product = conjugates
+            .iter()
+            .fold(ExtensionField::new(1, 0, 0, 0), |acc, c| acc * *c);
+        let sum = conjugates
+            .iter()
+            .fold(ExtensionField::new(0, 0, 0, 0), |acc, c| acc + *c);
+        assert_eq!(ExtensionField::from(qm.norm()), product);
+        assert_eq!(ExtensionField::from(qm.trace()), sum);
+
+        let cm_norm = qm * qm.frobenius(2);
+        assert_eq!(cm_norm, ExtensionField::from(qm.norm_cm31()));
+        assert_eq!(qm + qm.frobenius(2), ExtensionField::from(qm.trace_cm31()));
+    }
+
+    #[test]
+    fn test_structured_mul() {
+        let i = ExtensionField::new(0, 1, 0, 0);
+        let j = ExtensionField::new(0, 0, 1, 0);
+        let m = BaseField::new(8);
+        for qm in [
+            ExtensionField::new(1, 2, 3, 4),
+            ExtensionField::new(PRIME - 1, 0, 7, PRIME - 5),
+        ] {
+            assert_eq!(qm.mul_by_i(), qm * i);
+            assert_eq!(qm.mul_by_j(), qm * j);
+            assert_eq!(qm.mul_by_base_scalar(m), qm * ExtensionField::from(m));
+        }
+    }
+
+    #[test]
+    fn test_batch_inverse() {
+        let values: Vec<ExtensionField> = (1..50)
+            .map(|k| ExtensionField::new(k, 3 * k, PRIME - k, 7))
+            .collect();
+        let expected: Vec<ExtensionField> = values.iter().map(|v| v.inverse()).collect();
+        assert_eq!(ExtensionField::batch_inverse(&values), expected);
+
+        let mut in_place = values.clone();
+        ExtensionField::batch_inverse_in_place(&mut in_place);
+        assert_eq!(in_place, expected);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_random_sampling() {
+        use rand::rngs::SmallRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(0);
+        let x: ExtensionField = rng.gen();
+        let y: ExtensionField = rng.gen();
+        assert_ne!(x, y);
+        assert!(x.to_limbs().iter().all(|limb| limb.0 < PRIME));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let qm = ExtensionField::new(1, 2, 3, PRIME - 1);
+        let json = serde_json::to_string(&qm).unwrap();
+        assert_eq!(json, "[1,2,3,2147483646]");
+        assert_eq!(serde_json::from_str::<ExtensionField>(&json).unwrap(), qm);
+        assert!(serde_json::from_str::<ExtensionField>("[1,2,3,2147483647]").is_err());
+        assert!(serde_json::from_str::<ExtensionField>("[1,2,3]").is_err());
+    }
+
+    #[test]
+    fn test_try_inverse() {
+        let qm = ExtensionField::new(1, 2, 3, 4);
+        assert_eq!(qm.try_inverse(), Some(qm.inverse()));
+        assert_eq!(qm * qm.inverse(), ExtensionField::new(1, 0, 0, 0));
+        assert_eq!(ExtensionField::new(0, 0, 0, 0).try_inverse(), None);
+    }
+
+    #[test]
+    fn test_bytes() {
+        let qm = ExtensionField::new(1, 2, 3, PRIME - 1);
+        let bytes = qm.to_le_bytes();
+        assert_eq!(bytes[..8], [1, 0, 0, 0, 2, 0, 0, 0]);
+        assert_eq!(ExtensionField::from_le_bytes(bytes), Some(qm));
+
+        let mut bad = bytes;
+        bad[12..].copy_from_slice(&PRIME.to_le_bytes());
+        assert_eq!(ExtensionField::from_le_bytes(bad), None);
+
+        let digest: Vec<u8> = (0..32).collect();
+        let qm = ExtensionField::from_bytes_mod_order(&digest);
+        for (i, limb) in qm.to_limbs().iter().enumerate() {
+            assert_eq!(
+                *limb,
+                BaseField::from_bytes_mod_order(&digest[8 * i..8 * (i + 1)])
+            );
+        }
+
+        let digest: Vec<u8> = (0..33).collect();
+        let qm = ExtensionField::from_bytes_mod_order(&digest);
+        assert_eq!(qm.0 .0, BaseField::from_bytes_mod_order(&digest[..9]));
+        assert_eq!(qm.1 .1, BaseField::from_bytes_mod_order(&digest[25..]));
+        assert_eq!(
+            ExtensionField::from_bytes_mod_order(&[]),
+            ExtensionField::zero()
+        );
+        assert_eq!(
+            ExtensionField::from_bytes_mod_order(&[1, 2]),
+            ExtensionField::new(1, 2, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_sum_product() {
+        let qm0 = ExtensionField::new(1, 2, 3, 4);
+        let qm1 = ExtensionField::new(4, 5, 6, 7);
+        let values = [qm0, qm1, qm0];
+        assert_eq!(values.iter().sum::<ExtensionField>(), qm0 + qm1 + qm0);
+        assert_eq!(values.into_iter().sum::<ExtensionField>(), qm0 + qm1 + qm0);
+        assert_eq!(values.iter().product::<ExtensionField>(), qm0 * qm1 * qm0);
+        assert_eq!(
+            values.into_iter().product::<ExtensionField>(),
+            qm0 * qm1 * qm0
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            ExtensionField::new(1, 2, 3, PRIME - 1).to_string(),
+            "(1 + 2i) + (3 + 2147483646i)j"
+        );
+    }
+
+    #[test]
+    fn test_hex() {
+        let qm = ExtensionField::new(0x1234, 5, 0, PRIME - 1);
+        assert_eq!(qm.to_hex(), "0x1234+0x5i+0x0j+0x7ffffffeij");
+        assert_eq!(qm.to_hex().parse::<ExtensionField>(), Ok(qm));
+
+        assert_eq!(
+            "0x1+0x2i+0x3j".parse::<ExtensionField>(),
+            Err(ParseFieldError::InvalidFormat)
+        );
+        assert_eq!(
+            "0x1+0x2i+0x3j+0x4ij+0x5".parse::<ExtensionField>(),
+            Err(ParseFieldError::InvalidFormat)
+        );
+        assert_eq!(
+            "0x1+0x2j+0x3i+0x4ij".parse::<ExtensionField>(),
+            Err(ParseFieldError::InvalidFormat)
+        );
+        assert_eq!(
+            "0x1+0x7fffffffi+0x3j+0x4ij".parse::<ExtensionField>(),
+            Err(ParseFieldError::OutOfRange)
+        );
+        assert_eq!(
+            "0x1+0x02i+0x3j+0x4ij".parse::<ExtensionField>(),
+            Err(ParseFieldError::InvalidDigit)
+        );
+        assert_eq!(
+            "0x1+0x2i+0xAj+0x4ij".parse::<ExtensionField>(),
+            Err(ParseFieldError::InvalidDigit)
+        );
     }
 }
@@ -1,2 +1,186 @@
+use std::fmt::{self, Debug};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
 pub mod basefield;
+pub mod cm31;
 pub mod extensionfield;
+pub mod packed;
+
+/// Arithmetic shared by `BaseField`, `CM31` and `ExtensionField`, for code
+/// that is generic over the field it works in.
+pub trait Field:
+    Copy
+    + Debug
+    + Eq
+    + Add<Output = Self>
+    + AddAssign
+    + Sub<Output = Self>
+    + SubAssign
+    + Mul<Output = Self>
+    + MulAssign
+    + Neg<Output = Self>
+    + Div<Output = Self>
+    + DivAssign
+{
+    fn zero() -> Self;
+
+    fn one() -> Self;
+
+    /// Panics if `self` is zero.
+    fn inverse(&self) -> Self;
+
+    /// Returns `None` if `self` is zero.
+    fn try_inverse(&self) -> Option<Self>;
+
+    fn pow(&self, exp: u128) -> Self;
+
+    fn square(self) -> Self;
+}
+
+/// Inverts every element of `values` in place with Montgomery's trick: one
+/// inversion plus `3 * (n - 1)` multiplications.
+///
+/// Panics if any element is zero.
+pub fn batch_inverse_in_place<F: Field>(values: &mut [F]) {
+    if values.is_empty() {
+        return;
+    }
+    // prefix[i] = values[0] * ... * values[i - 1].
+    let mut prefix = Vec::with_capacity(values.len());
+    let mut acc = F::one();
+    for v in values.iter() {
+        prefix.push(acc);
+        acc *= *v;
+    }
+    let mut inv = acc.inverse();
+    for (v, p) in values.iter_mut().zip(prefix).rev() {
+        let next = inv * *v;
+        *v = inv * p;
+        inv = next;
+    }
+}
+
+/// Out-of-place version of `batch_inverse_in_place`.
+pub fn batch_inverse<F: Field>(values: &[F]) -> Vec<F> {
+    let mut res = values.to_vec();
+    batch_inverse_in_place(&mut res);
+    res
+}
+
+/// Error returned when parsing a field element from its hex form.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseFieldError {
+    /// A limb did not start with `0x`.
+    MissingPrefix,
+    /// A limb was empty, contained a character other than `0-9a-f`, or had
+    /// a leading zero.
+    InvalidDigit,
+    /// A limb was not in `[0, PRIME)`.
+    OutOfRange,
+    /// An extension element did not have the `a+bi+cj+dij` shape.
+    InvalidFormat,
+}
+
+impl fmt::Display for ParseFieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseFieldError::MissingPrefix => write!(f, "missing 0x prefix"),
+            ParseFieldError::InvalidDigit => write!(f, "invalid hex digit"),
+            ParseFieldError::OutOfRange => write!(f, "value is not below PRIME"),
+            ParseFieldError::InvalidFormat => write!(f, "expected 0xa+0xbi+0xcj+0xdij"),
+        }
+    }
+}
+
+impl std::error::Error for ParseFieldError {}
+
+#[cfg(test)]
+mod tests {
+    use super::basefield::{BaseField, PRIME};
+    use super::cm31::CM31;
+    use super::extensionfield::ExtensionField;
+    use super::Field;
+
+    fn sum_of_inverses<F: Field>(values: &[F]) -> F {
+        let mut acc = F::zero();
+        for v in values {
+            acc += v.inverse();
+        }
+        acc
+    }
+
+    fn check_axioms<F: Field>(x: F) {
+        assert_eq!(x + F::zero(), x);
+        assert_eq!(x * F::one(), x);
+        assert_eq!(x * x.inverse(), F::one());
+        assert_eq!(x.square(), x.pow(2));
+        assert_eq!(x + (-x), F::zero());
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn test_num_traits() {
+        use num_traits::{Inv, One, Zero};
+
+        fn check<F: Field + Zero + One + Inv<Output = F>>(x: F) {
+            assert!(<F as Zero>::zero().is_zero());
+            assert!(!x.is_zero());
+            assert_eq!(<F as One>::one(), <F as Field>::one());
+            assert_eq!(x.inv(), x.inverse());
+        }
+        check(BaseField::new(7));
+        check(ExtensionField::new(1, 2, 3, 4));
+    }
+
+    #[test]
+    fn test_hash_ord_default() {
+        use std::collections::{BTreeMap, HashMap};
+
+        assert_eq!(BaseField::default(), BaseField::zero());
+        assert_eq!(CM31::default(), CM31::zero());
+        assert_eq!(ExtensionField::default(), ExtensionField::zero());
+
+        let mut counts = HashMap::new();
+        for x in [1, 2, 1, PRIME + 1] {
+            *counts.entry(BaseField::new(x)).or_insert(0) += 1;
+        }
+        assert_eq!(counts[&BaseField::new(1)], 3);
+
+        assert!(BaseField::new(2) < BaseField::new(PRIME - 1));
+        assert!(ExtensionField::new(1, 9, 9, 9) < ExtensionField::new(2, 0, 0, 0));
+        assert!(ExtensionField::new(1, 2, 3, 4) < ExtensionField::new(1, 2, 3, 5));
+        let sorted: Vec<ExtensionField> = BTreeMap::from([
+            (ExtensionField::new(0, 0, 1, 0), ()),
+            (ExtensionField::new(0, 1, 0, 0), ()),
+        ])
+        .into_keys()
+        .collect();
+        assert_eq!(
+            sorted,
+            [
+                ExtensionField::new(0, 0, 1, 0),
+                ExtensionField::new(0, 1, 0, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_generic_field() {
+        check_axioms(BaseField::new(7));
+        check_axioms(ExtensionField::new(1, 2, 3, 4));
+
+        let base = [BaseField::new(2), BaseField::new(4)];
+        assert_eq!(
+            sum_of_inverses(&base),
+            BaseField::new(3) / BaseField::new(4)
+        );
+        let ext = [
+            ExtensionField::new(2, 0, 0, 0),
+            ExtensionField::new(4, 0, 0, 0),
+        ];
+        assert_eq!(
+            sum_of_inverses(&ext),
+            ExtensionField::new(3, 0, 0, 0) / BaseField::new(4)
+        );
+    }
+}
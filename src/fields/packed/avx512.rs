@@ -0,0 +1,131 @@
+use std::arch::x86_64::*;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::fields::basefield::{BaseField, PRIME};
+
+pub const LANES: usize = 16;
+
+/// `LANES` base field elements packed into a 512-bit register. Every lane
+/// is kept canonical, i.e. in `[0, PRIME)`.
+#[derive(Clone, Copy, Debug)]
+pub struct PackedBaseField(__m512i);
+
+impl PackedBaseField {
+    pub fn broadcast(value: BaseField) -> Self {
+        unsafe { PackedBaseField(_mm512_set1_epi32(value.0 as i32)) }
+    }
+
+    pub fn from_array(values: [BaseField; LANES]) -> Self {
+        unsafe { PackedBaseField(_mm512_loadu_si512(values.as_ptr() as *const __m512i)) }
+    }
+
+    pub fn to_array(self) -> [BaseField; LANES] {
+        let mut res = [BaseField(0); LANES];
+        unsafe { _mm512_storeu_si512(res.as_mut_ptr() as *mut __m512i, self.0) };
+        res
+    }
+
+    /// Loads the first `LANES` elements of `values`.
+    /// Panics if the slice is shorter than `LANES`.
+    pub fn load(values: &[BaseField]) -> Self {
+        Self::from_array(values[..LANES].try_into().unwrap())
+    }
+
+    /// Stores into the first `LANES` elements of `dst`.
+    /// Panics if the slice is shorter than `LANES`.
+    pub fn store(self, dst: &mut [BaseField]) {
+        dst[..LANES].copy_from_slice(&self.to_array());
+    }
+
+    /// Lane-wise `min(val, val - PRIME)`, bringing `[0, 2 * PRIME)` to
+    /// `[0, PRIME)` like `BaseField::reduce_once`.
+    fn reduce_once(val: __m512i) -> __m512i {
+        unsafe { _mm512_min_epu32(val, _mm512_sub_epi32(val, _mm512_set1_epi32(PRIME as i32))) }
+    }
+
+    /// Folds 64-bit products `< 2^62` to `lo + hi < 2 * PRIME` in the low
+    /// 32 bits of each 64-bit lane; the high 32 bits end up zero.
+    fn fold_products(prod: __m512i) -> __m512i {
+        unsafe {
+            let lo = _mm512_and_si512(prod, _mm512_set1_epi64(PRIME as i64));
+            let hi = _mm512_srli_epi64::<31>(prod);
+            _mm512_add_epi64(lo, hi)
+        }
+    }
+}
+
+impl PartialEq for PackedBaseField {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_array() == other.to_array()
+    }
+}
+
+impl Eq for PackedBaseField {}
+
+impl Add for PackedBaseField {
+    type Output = PackedBaseField;
+
+    fn add(self, other: PackedBaseField) -> PackedBaseField {
+        unsafe { PackedBaseField(Self::reduce_once(_mm512_add_epi32(self.0, other.0))) }
+    }
+}
+
+impl AddAssign for PackedBaseField {
+    fn add_assign(&mut self, other: PackedBaseField) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for PackedBaseField {
+    type Output = PackedBaseField;
+
+    fn sub(self, other: PackedBaseField) -> PackedBaseField {
+        unsafe {
+            let diff = _mm512_sub_epi32(self.0, other.0);
+            let wrapped = _mm512_add_epi32(diff, _mm512_set1_epi32(PRIME as i32));
+            PackedBaseField(_mm512_min_epu32(diff, wrapped))
+        }
+    }
+}
+
+impl SubAssign for PackedBaseField {
+    fn sub_assign(&mut self, other: PackedBaseField) {
+        *self = *self - other;
+    }
+}
+
+impl Mul for PackedBaseField {
+    type Output = PackedBaseField;
+
+    fn mul(self, other: PackedBaseField) -> PackedBaseField {
+        unsafe {
+            // `_mm512_mul_epu32` only multiplies the even 32-bit lanes, so the
+            // odd lanes are shifted down and multiplied separately.
+            let prod_even = _mm512_mul_epu32(self.0, other.0);
+            let prod_odd = _mm512_mul_epu32(
+                _mm512_srli_epi64::<32>(self.0),
+                _mm512_srli_epi64::<32>(other.0),
+            );
+            let even = Self::fold_products(prod_even);
+            let odd = _mm512_slli_epi64::<32>(Self::fold_products(prod_odd));
+            PackedBaseField(Self::reduce_once(_mm512_or_si512(even, odd)))
+        }
+    }
+}
+
+impl MulAssign for PackedBaseField {
+    fn mul_assign(&mut self, other: PackedBaseField) {
+        *self = *self * other;
+    }
+}
+
+impl Neg for PackedBaseField {
+    type Output = PackedBaseField;
+
+    fn neg(self) -> PackedBaseField {
+        unsafe {
+            let prime = _mm512_set1_epi32(PRIME as i32);
+            PackedBaseField(Self::reduce_once(_mm512_sub_epi32(prime, self.0)))
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use std::array;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use super::{PackedBaseField, LANES};
+use crate::fields::extensionfield::ExtensionField;
+
+/// `LANES` extension field elements stored as four packed limb columns, so
+/// lane `k` is `(a[k] + b[k]i) + (c[k] + d[k]i)j` for limbs `[a, b, c, d]`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PackedExtensionField(pub [PackedBaseField; 4]);
+
+type PackedCM31 = (PackedBaseField, PackedBaseField);
+
+impl PackedExtensionField {
+    pub fn broadcast(value: ExtensionField) -> Self {
+        PackedExtensionField(value.to_limbs().map(PackedBaseField::broadcast))
+    }
+
+    pub fn from_array(values: [ExtensionField; LANES]) -> Self {
+        let limbs = values.map(|v| v.to_limbs());
+        PackedExtensionField(array::from_fn(|i| {
+            PackedBaseField::from_array(array::from_fn(|k| limbs[k][i]))
+        }))
+    }
+
+    pub fn to_array(self) -> [ExtensionField; LANES] {
+        let limbs = self.0.map(PackedBaseField::to_array);
+        array::from_fn(|k| ExtensionField::from_limbs(array::from_fn(|i| limbs[i][k])))
+    }
+
+    /// Lane-wise `ExtensionField::combine`: lane `k` of the result combines
+    /// lane `k` of every element of `values` with the same `alpha`.
+    pub fn combine(values: &[PackedExtensionField], alpha: ExtensionField) -> Self {
+        let alpha = Self::broadcast(alpha);
+        let zero = Self::broadcast(ExtensionField::new(0, 0, 0, 0));
+        values.iter().fold(zero, |acc, &v| acc * alpha + v)
+    }
+
+    /// Multiplies every lane by the matching lane of a packed base field.
+    pub fn mul_by_packed_base(self, scalar: PackedBaseField) -> Self {
+        PackedExtensionField(self.0.map(|limb| limb * scalar))
+    }
+
+    fn mul_cm31(x: PackedCM31, y: PackedCM31) -> PackedCM31 {
+        (x.0 * y.0 - x.1 * y.1, x.0 * y.1 + x.1 * y.0)
+    }
+
+    /// `(x + yi)(2 + i) = (2x - y) + (x + 2y)i`.
+    fn mul_by_nonresidue(v: PackedCM31) -> PackedCM31 {
+        (v.0 + v.0 - v.1, v.0 + v.1 + v.1)
+    }
+}
+
+impl Add for PackedExtensionField {
+    type Output = PackedExtensionField;
+
+    fn add(self, other: PackedExtensionField) -> PackedExtensionField {
+        PackedExtensionField(array::from_fn(|i| self.0[i] + other.0[i]))
+    }
+}
+
+impl AddAssign for PackedExtensionField {
+    fn add_assign(&mut self, other: PackedExtensionField) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for PackedExtensionField {
+    type Output = PackedExtensionField;
+
+    fn sub(self, other: PackedExtensionField) -> PackedExtensionField {
+        PackedExtensionField(array::from_fn(|i| self.0[i] - other.0[i]))
+    }
+}
+
+impl SubAssign for PackedExtensionField {
+    fn sub_assign(&mut self, other: PackedExtensionField) {
+        *self = *self - other;
+    }
+}
+
+impl Mul for PackedExtensionField {
+    type Output = PackedExtensionField;
+
+    fn mul(self, other: PackedExtensionField) -> PackedExtensionField {
+        // Same Karatsuba layout as `ExtensionField`, with x = x0 + x1 j and
+        // y = y0 + y1 j split into their CM31 halves.
+        let [a, b, c, d] = self.0;
+        let [e, f, g, h] = other.0;
+        let x0y0 = Self::mul_cm31((a, b), (e, f));
+        let x1y1 = Self::mul_cm31((c, d), (g, h));
+        let sum = Self::mul_cm31((a + c, b + d), (e + g, f + h));
+        let r_x1y1 = Self::mul_by_nonresidue(x1y1);
+        PackedExtensionField([
+            x0y0.0 + r_x1y1.0,
+            x0y0.1 + r_x1y1.1,
+            sum.0 - x0y0.0 - x1y1.0,
+            sum.1 - x0y0.1 - x1y1.1,
+        ])
+    }
+}
+
+impl MulAssign for PackedExtensionField {
+    fn mul_assign(&mut self, other: PackedExtensionField) {
+        *self = *self * other;
+    }
+}
+
+impl Neg for PackedExtensionField {
+    type Output = PackedExtensionField;
+
+    fn neg(self) -> PackedExtensionField {
+        PackedExtensionField(self.0.map(|limb| -limb))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fields::basefield::BaseField;
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    fn random_array(rng: &mut SmallRng) -> [ExtensionField; LANES] {
+        array::from_fn(|_| ExtensionField::new(rng.gen(), rng.gen(), rng.gen(), rng.gen()))
+    }
+
+    #[test]
+    fn test_ops() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        for _ in 0..1000 {
+            let x = random_array(&mut rng);
+            let y = random_array(&mut rng);
+            let px = PackedExtensionField::from_array(x);
+            let py = PackedExtensionField::from_array(y);
+            assert_eq!(px.to_array(), x);
+            assert_eq!((px + py).to_array(), array::from_fn(|k| x[k] + y[k]));
+            assert_eq!((px - py).to_array(), array::from_fn(|k| x[k] - y[k]));
+            assert_eq!((px * py).to_array(), array::from_fn(|k| x[k] * y[k]));
+            assert_eq!((-px).to_array(), array::from_fn(|k| -x[k]));
+        }
+    }
+
+    #[test]
+    fn test_combine() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let alpha = ExtensionField::new(rng.gen(), rng.gen(), rng.gen(), rng.gen());
+        let columns: Vec<[ExtensionField; LANES]> =
+            (0..5).map(|_| random_array(&mut rng)).collect();
+        let packed: Vec<PackedExtensionField> = columns
+            .iter()
+            .map(|&c| PackedExtensionField::from_array(c))
+            .collect();
+        let expected: [ExtensionField; LANES] = array::from_fn(|k| {
+            let row: Vec<ExtensionField> = columns.iter().map(|c| c[k]).collect();
+            ExtensionField::combine(&row, alpha)
+        });
+        assert_eq!(
+            PackedExtensionField::combine(&packed, alpha).to_array(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_mul_by_packed_base() {
+        let x = ExtensionField::new(1, 2, 3, 4);
+        let m = BaseField::new(8);
+        let packed =
+            PackedExtensionField::broadcast(x).mul_by_packed_base(PackedBaseField::broadcast(m));
+        assert_eq!(packed.to_array(), [x * m; LANES]);
+    }
+}
@@ -0,0 +1,111 @@
+//! Packed base field types for SIMD code paths. The lane count depends on
+//! the instruction set the crate is compiled for; AVX-512 is preferred over
+//! AVX2 when both are enabled.
+
+#[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+pub mod avx2;
+#[cfg(all(target_arch = "x86_64", target_feature = "avx512f"))]
+pub mod avx512;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+pub mod neon;
+
+#[cfg(all(
+    target_arch = "x86_64",
+    target_feature = "avx2",
+    not(target_feature = "avx512f")
+))]
+pub use avx2::{PackedBaseField, LANES};
+#[cfg(all(target_arch = "x86_64", target_feature = "avx512f"))]
+pub use avx512::{PackedBaseField, LANES};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+pub use neon::{PackedBaseField, LANES};
+
+#[cfg(any(
+    all(target_arch = "x86_64", target_feature = "avx2"),
+    all(target_arch = "aarch64", target_feature = "neon")
+))]
+pub mod extensionfield;
+
+#[cfg(all(
+    test,
+    any(
+        all(target_arch = "x86_64", target_feature = "avx2"),
+        all(target_arch = "aarch64", target_feature = "neon")
+    )
+))]
+mod tests {
+    /// Checks a `PackedBaseField` backend lane-wise against `BaseField`. Every
+    /// compiled backend runs the same suite.
+    macro_rules! packed_base_field_tests {
+        ($backend:ident) => {
+            mod $backend {
+                use super::super::$backend::{PackedBaseField, LANES};
+                use crate::fields::basefield::{BaseField, PRIME};
+                use rand::rngs::SmallRng;
+                use rand::{Rng, SeedableRng};
+
+                fn random_array(rng: &mut SmallRng) -> [BaseField; LANES] {
+                    std::array::from_fn(|_| BaseField::new(rng.gen::<u32>()))
+                }
+
+                #[test]
+                fn test_ops() {
+                    let mut rng = SmallRng::seed_from_u64(0);
+                    for _ in 0..1000 {
+                        let x = random_array(&mut rng);
+                        let y = random_array(&mut rng);
+                        let px = PackedBaseField::from_array(x);
+                        let py = PackedBaseField::from_array(y);
+                        assert_eq!((px + py).to_array(), std::array::from_fn(|i| x[i] + y[i]));
+                        assert_eq!((px - py).to_array(), std::array::from_fn(|i| x[i] - y[i]));
+                        assert_eq!((px * py).to_array(), std::array::from_fn(|i| x[i] * y[i]));
+                        assert_eq!((-px).to_array(), std::array::from_fn(|i| -x[i]));
+                    }
+                }
+
+                #[test]
+                fn test_edge_cases() {
+                    let zero = PackedBaseField::broadcast(BaseField::new(0));
+                    let max = PackedBaseField::broadcast(BaseField::new(PRIME - 1));
+                    let one = PackedBaseField::broadcast(BaseField::new(1));
+                    assert_eq!(max + one, zero);
+                    assert_eq!(zero - one, max);
+                    assert_eq!(max * max, one);
+                    assert_eq!(-zero, zero);
+                }
+
+                #[test]
+                fn test_load_store() {
+                    let n = LANES + 2;
+                    let values: Vec<BaseField> = (0..n as u32).map(BaseField::new).collect();
+                    let packed = PackedBaseField::load(&values[2..]);
+                    let mut dst = vec![BaseField::new(0); n];
+                    packed.store(&mut dst[1..]);
+                    assert_eq!(dst[1..n - 1], values[2..n]);
+                    assert_eq!(dst[0], BaseField::new(0));
+                    assert_eq!(dst[n - 1], BaseField::new(0));
+                }
+
+                #[test]
+                #[should_panic]
+                fn test_load_short_slice() {
+                    PackedBaseField::load(&[BaseField::new(0); LANES - 1]);
+                }
+
+                #[test]
+                #[should_panic]
+                fn test_store_short_slice() {
+                    let packed = PackedBaseField::broadcast(BaseField::new(1));
+                    packed.store(&mut [BaseField::new(0); LANES - 1]);
+                }
+            }
+        };
+    }
+
+    #[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+    packed_base_field_tests!(avx2);
+    #[cfg(all(target_arch = "x86_64", target_feature = "avx512f"))]
+    packed_base_field_tests!(avx512);
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    packed_base_field_tests!(neon);
+}
@@ -0,0 +1,126 @@
+use std::arch::aarch64::*;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::fields::basefield::{BaseField, PRIME};
+
+pub const LANES: usize = 4;
+
+/// `LANES` base field elements packed into a 128-bit NEON register. Every
+/// lane is kept canonical, i.e. in `[0, PRIME)`.
+#[derive(Clone, Copy, Debug)]
+pub struct PackedBaseField(uint32x4_t);
+
+impl PackedBaseField {
+    pub fn broadcast(value: BaseField) -> Self {
+        unsafe { PackedBaseField(vdupq_n_u32(value.0)) }
+    }
+
+    pub fn from_array(values: [BaseField; LANES]) -> Self {
+        unsafe { PackedBaseField(vld1q_u32(values.as_ptr() as *const u32)) }
+    }
+
+    pub fn to_array(self) -> [BaseField; LANES] {
+        let mut res = [BaseField(0); LANES];
+        unsafe { vst1q_u32(res.as_mut_ptr() as *mut u32, self.0) };
+        res
+    }
+
+    /// Loads the first `LANES` elements of `values`.
+    /// Panics if the slice is shorter than `LANES`.
+    pub fn load(values: &[BaseField]) -> Self {
+        Self::from_array(values[..LANES].try_into().unwrap())
+    }
+
+    /// Stores into the first `LANES` elements of `dst`.
+    /// Panics if the slice is shorter than `LANES`.
+    pub fn store(self, dst: &mut [BaseField]) {
+        dst[..LANES].copy_from_slice(&self.to_array());
+    }
+
+    /// Lane-wise `min(val, val - PRIME)`, bringing `[0, 2 * PRIME)` to
+    /// `[0, PRIME)` like `BaseField::reduce_once`.
+    fn reduce_once(val: uint32x4_t) -> uint32x4_t {
+        unsafe { vminq_u32(val, vsubq_u32(val, vdupq_n_u32(PRIME))) }
+    }
+
+    /// Folds two 64-bit products `< 2^62` to `lo + hi < 2 * PRIME` and
+    /// narrows them to 32 bits.
+    fn fold_products(prod: uint64x2_t) -> uint32x2_t {
+        unsafe {
+            let lo = vandq_u64(prod, vdupq_n_u64(PRIME as u64));
+            let hi = vshrq_n_u64::<31>(prod);
+            vmovn_u64(vaddq_u64(lo, hi))
+        }
+    }
+}
+
+impl PartialEq for PackedBaseField {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_array() == other.to_array()
+    }
+}
+
+impl Eq for PackedBaseField {}
+
+impl Add for PackedBaseField {
+    type Output = PackedBaseField;
+
+    fn add(self, other: PackedBaseField) -> PackedBaseField {
+        unsafe { PackedBaseField(Self::reduce_once(vaddq_u32(self.0, other.0))) }
+    }
+}
+
+impl AddAssign for PackedBaseField {
+    fn add_assign(&mut self, other: PackedBaseField) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for PackedBaseField {
+    type Output = PackedBaseField;
+
+    fn sub(self, other: PackedBaseField) -> PackedBaseField {
+        unsafe {
+            let diff = vsubq_u32(self.0, other.0);
+            let wrapped = vaddq_u32(diff, vdupq_n_u32(PRIME));
+            PackedBaseField(vminq_u32(diff, wrapped))
+        }
+    }
+}
+
+impl SubAssign for PackedBaseField {
+    fn sub_assign(&mut self, other: PackedBaseField) {
+        *self = *self - other;
+    }
+}
+
+impl Mul for PackedBaseField {
+    type Output = PackedBaseField;
+
+    fn mul(self, other: PackedBaseField) -> PackedBaseField {
+        unsafe {
+            // Widening multiplies of the low and high lane pairs.
+            let prod_low = vmull_u32(vget_low_u32(self.0), vget_low_u32(other.0));
+            let prod_high = vmull_high_u32(self.0, other.0);
+            let folded = vcombine_u32(
+                Self::fold_products(prod_low),
+                Self::fold_products(prod_high),
+            );
+            PackedBaseField(Self::reduce_once(folded))
+        }
+    }
+}
+
+impl MulAssign for PackedBaseField {
+    fn mul_assign(&mut self, other: PackedBaseField) {
+        *self = *self * other;
+    }
+}
+
+impl Neg for PackedBaseField {
+    type Output = PackedBaseField;
+
+    fn neg(self) -> PackedBaseField {
+        unsafe { PackedBaseField(Self::reduce_once(vsubq_u32(vdupq_n_u32(PRIME), self.0))) }
+    }
+}
@@ -1,3 +1,5 @@
+pub mod bitstream;
+pub mod circle;
 pub mod fields;
 
 #[cfg(test)]